@@ -32,6 +32,14 @@ pub fn render(world: &mut World) {
         if ui.add(Button::new("Hello world!")).clicked() {
             ui.resource_mut::<Counter>().0 += 1;
         }
+        ui.add_with(
+            Button::new("Reset"),
+            SetResource::new(|counter: &mut Counter, response| {
+                if response.clicked() {
+                    counter.0 = 0;
+                }
+            }),
+        );
 
         fn my_widget(InMut(ui): InMut<Ui>, counter: Res<Counter>) -> Response {
             ui.label(format!("Clicked: {}", counter.0))
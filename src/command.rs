@@ -0,0 +1,144 @@
+//! Deferred UI that can be recorded from regular systems running in parallel.
+//!
+//! [`UiCommands`] records UI into a per-system buffer instead of drawing it
+//! directly, so systems authoring UI don't need exclusive [`World`] access.
+//! The buffers are applied like [`Commands`], moving the recorded UI into the
+//! [`DeferredUi`] resource, and [`show_deferred_ui`] (or
+//! [`WorldCtx::show_deferred`]) then shows it in the current egui pass with
+//! [`World`] access.
+//!
+//! # Ordering
+//!
+//! Buffers are applied in the order of their systems in the schedule, so UI
+//! recorded by a system ordered before another one (e.g. with `.before` or
+//! system sets) is shown first, and UI recorded within a system is shown in
+//! the order it was recorded. Systems without an explicit ordering are applied
+//! in an unspecified order, which matters for overlapping areas.
+//!
+//! # Single-pass immediate mode
+//!
+//! egui runs a single pass per frame, so [`show_deferred_ui`] needs to run
+//! after the systems recording UI but within that pass, e.g. in `Update` or
+//! in `PostUpdate` before `bevy_egui`'s `EguiSet::ProcessOutput`. The recorded
+//! closures are then run like any other UI, and see the same frame's input and
+//! world state. The recording systems themselves can't observe responses:
+//! anything a closure wants them to know, e.g. that a button was clicked, has
+//! to be written back to the [`World`], and is seen by them in the next frame.
+//!
+//! # Example
+//!
+//! ```
+//! use bevy_ecs::system::{Res, Resource};
+//! use egui::Window;
+//! use evergreen_egui::prelude::*;
+//!
+//! #[derive(Resource)]
+//! struct Score(u32);
+//!
+//! #[derive(Resource, Default)]
+//! struct ResetRequested(bool);
+//!
+//! fn score_window(score: Res<Score>, mut ui: UiCommands) {
+//!     let score = score.0;
+//!     ui.show(Window::new("Score"), move |mut ui| {
+//!         ui.ui_mut().label(format!("Score: {score}"));
+//!         if ui.ui_mut().button("Reset").clicked() {
+//!             ui.resource_mut::<ResetRequested>().0 = true;
+//!         }
+//!     });
+//! }
+//! ```
+//!
+//! [`Commands`]: bevy_ecs::system::Commands
+
+use std::mem;
+
+use bevy_ecs::{
+    system::{Deferred, Resource, SystemBuffer, SystemMeta, SystemParam},
+    world::World,
+};
+
+use crate::{ctx::WorldCtx, root::Root, ui::WorldUi};
+
+/// UI recorded by [`UiCommands`], shown later with [`World`] access.
+pub(crate) type UiCommand = Box<dyn FnOnce(&mut WorldCtx) + Send + Sync>;
+
+/// [`SystemParam`] for recording UI from regular systems. See the
+/// [module documentation](self) for how the recorded UI is ordered and shown.
+#[derive(SystemParam)]
+pub struct UiCommands<'s> {
+    queue: Deferred<'s, UiCommandQueue>,
+}
+
+impl UiCommands<'_> {
+    /// Records showing a root container, like [`WorldCtx::show`].
+    pub fn show<Ro: Root + Send + Sync + 'static>(
+        &mut self,
+        root: Ro,
+        f: impl FnOnce(WorldUi<'_, '_, Ro::Ui>) + Send + Sync + 'static,
+    ) {
+        self.add(move |ctx| {
+            ctx.show(root, f);
+        });
+    }
+
+    /// Records a closure that is called with the [`WorldCtx`] of the primary
+    /// window, e.g. to show several roots that depend on each other.
+    pub fn add(&mut self, f: impl FnOnce(&mut WorldCtx) + Send + Sync + 'static) {
+        self.queue.0.push(Box::new(f));
+    }
+}
+
+/// Per-system buffer of the UI recorded by [`UiCommands`].
+#[derive(Default)]
+pub struct UiCommandQueue(Vec<UiCommand>);
+
+impl SystemBuffer for UiCommandQueue {
+    fn apply(&mut self, _system_meta: &SystemMeta, world: &mut World) {
+        world
+            .get_resource_or_init::<DeferredUi>()
+            .0
+            .append(&mut self.0);
+    }
+}
+
+/// [`Resource`] with the UI recorded by [`UiCommands`] that hasn't been shown
+/// yet, in the order it will be shown in.
+#[derive(Resource, Default)]
+pub struct DeferredUi(Vec<UiCommand>);
+
+impl DeferredUi {
+    /// Returns the number of recorded closures that haven't been shown yet.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there is no recorded UI to show.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Removes all recorded UI without showing it.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Removes and returns the recorded UI in the order it will be shown in.
+    pub(crate) fn take(&mut self) -> Vec<UiCommand> {
+        mem::take(&mut self.0)
+    }
+}
+
+/// Exclusive system that shows the UI recorded by [`UiCommands`] in the
+/// primary window with [`WorldCtx::show_deferred`]. See the
+/// [module documentation](self) for where to add it.
+///
+/// If there is no primary window, the recorded UI is discarded instead, so it
+/// doesn't pile up.
+pub fn show_deferred_ui(world: &mut World) {
+    if let Some(mut ctx) = WorldCtx::new(world) {
+        ctx.show_deferred();
+    } else if let Some(mut deferred) = world.get_resource_mut::<DeferredUi>() {
+        deferred.clear();
+    }
+}
@@ -1,8 +1,11 @@
 //! Containers for grouping widgets together.
 
+use std::hash::Hash;
+
 use egui::{
-    menu::SubMenu, scroll_area::ScrollAreaOutput, CollapsingHeader, CollapsingResponse, ComboBox,
-    Frame, InnerResponse, Layout, Resize, ScrollArea, Ui, UiBuilder,
+    menu::SubMenu, pos2, scroll_area::ScrollAreaOutput, vec2, Align, CollapsingHeader,
+    CollapsingResponse, ComboBox, CursorIcon, Frame, Id, InnerResponse, Key, Layout, Rect, Resize,
+    ScrollArea, Sense, TextWrapMode, Ui, UiBuilder, Vec2,
 };
 
 use crate::ui::WorldUi;
@@ -24,6 +27,45 @@ pub trait Container {
     ) -> Self::Out<R>;
 }
 
+/// Shows the container if it's [`Some`], or calls the closure directly with
+/// the parent [`WorldUi`] otherwise, e.g. to conditionally wrap contents in a
+/// [`Frame`].
+impl<C: Container<Ui = Ui>> Container for Option<C> {
+    type Ui = Ui;
+    type Out<R> = MaybeWrapped<C::Out<R>, R>;
+
+    fn show<'world, R>(
+        self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        match self {
+            Some(container) => MaybeWrapped::Wrapped(container.show(ui, f)),
+            None => MaybeWrapped::Unwrapped(f(ui)),
+        }
+    }
+}
+
+/// Output of an optional [`Container`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaybeWrapped<O, R> {
+    /// The container was shown, with the given output.
+    Wrapped(O),
+    /// There was no container, and the closure returned the given value.
+    Unwrapped(R),
+}
+
+impl<R> MaybeWrapped<InnerResponse<R>, R> {
+    /// Returns the closure's return value, whether the container was shown or
+    /// not.
+    pub fn inner(self) -> R {
+        match self {
+            MaybeWrapped::Wrapped(response) => response.inner,
+            MaybeWrapped::Unwrapped(inner) => inner,
+        }
+    }
+}
+
 impl Container for Layout {
     type Ui = Ui;
     type Out<R> = InnerResponse<R>;
@@ -41,6 +83,29 @@ impl Container for Layout {
     }
 }
 
+/// [`Container`] like [`Layout`] that also returns the [`Ui::min_rect`] of the
+/// content, measured after the closure has run. Unlike the rect of the
+/// [`InnerResponse`], this only covers the space the content actually used.
+pub struct MeasuredLayout(pub Layout);
+
+impl Container for MeasuredLayout {
+    type Ui = Ui;
+    type Out<R> = (R, Rect);
+
+    fn show<'world, R>(
+        self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let (world, ui) = ui.into_parts();
+        ui.with_layout(self.0, |ui| {
+            let out = f(WorldUi::new(world, &mut *ui));
+            (out, ui.min_rect())
+        })
+        .inner
+    }
+}
+
 impl Container for ComboBox {
     type Ui = Ui;
     type Out<R> = InnerResponse<Option<R>>;
@@ -144,7 +209,8 @@ impl Container for SubMenu {
 }
 
 /// [`Container`] that renders `COLS` columns. `COLS` can either be a
-/// runtime-specified `usize` or a compile-time-specified [`Const<N>`].
+/// runtime-specified `usize`, a compile-time-specified [`Const<N>`], or a
+/// `&[f32]` of weights for columns of different widths.
 pub struct Columns<COLS>(pub COLS);
 
 impl Container for Columns<usize> {
@@ -164,6 +230,59 @@ impl Container for Columns<usize> {
     }
 }
 
+/// Columns with widths proportional to the given weights, e.g. `&[1.0, 3.0]`
+/// for a narrow icon column next to a wide label column. Negative weights are
+/// treated as zero, and if all weights are zero, all columns are empty.
+impl Container for Columns<&[f32]> {
+    type Ui = [Ui];
+    type Out<R> = R;
+
+    fn show<'world, R>(
+        self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let (world, ui) = ui.into_parts();
+        let weights = self.0;
+        let spacing = ui.spacing().item_spacing.x;
+        let gaps = weights.len().saturating_sub(1) as f32;
+        let width = (ui.available_width() - spacing * gaps).max(0.0);
+        let total: f32 = weights.iter().map(|weight| weight.max(0.0)).sum();
+        let top_left = ui.cursor().min;
+        let height = ui.available_height();
+
+        let mut x = top_left.x;
+        let mut columns: Vec<Ui> = weights
+            .iter()
+            .map(|weight| {
+                let column_width = if total > 0.0 {
+                    width * weight.max(0.0) / total
+                } else {
+                    0.0
+                };
+                let rect = Rect::from_min_size(pos2(x, top_left.y), vec2(column_width, height));
+                x += column_width + spacing;
+                let mut column = ui.new_child(
+                    UiBuilder::new()
+                        .max_rect(rect)
+                        .layout(Layout::top_down_justified(Align::LEFT)),
+                );
+                column.set_width(column_width);
+                column
+            })
+            .collect();
+
+        let out = f(WorldUi::new(world, &mut columns[..]));
+        let max_height = columns
+            .iter()
+            .map(|column| column.min_rect().height())
+            .fold(0.0, f32::max);
+        let rect = Rect::from_min_size(top_left, vec2(width + spacing * gaps, max_height));
+        ui.advance_cursor_after_rect(rect);
+        out
+    }
+}
+
 /// Specifies a constant number of [`Columns`].
 pub struct Const<const N: usize>;
 
@@ -204,6 +323,31 @@ impl Container for Enabled {
     }
 }
 
+/// [`Container`] that renders a maybe-visible UI. Invisible contents still
+/// take up space, so the surrounding layout doesn't shift.
+pub struct Visible(pub bool);
+
+impl Container for Visible {
+    type Ui = Ui;
+    type Out<R> = InnerResponse<R>;
+
+    fn show<'world, R>(
+        self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let (world, ui) = ui.into_parts();
+        let mut builder = UiBuilder::new();
+        if !self.0 {
+            builder = builder.invisible();
+        }
+        ui.scope_builder(builder, |ui| {
+            let ui = WorldUi::new(world, ui);
+            f(ui)
+        })
+    }
+}
+
 impl Container for UiBuilder {
     type Ui = Ui;
     type Out<R> = InnerResponse<R>;
@@ -221,6 +365,86 @@ impl Container for UiBuilder {
     }
 }
 
+/// [`Container`] that sets the [`TextWrapMode`] of its contents. The mode of
+/// the surrounding [`Ui`] is left untouched.
+pub struct TextWrap(pub TextWrapMode);
+
+impl Container for TextWrap {
+    type Ui = Ui;
+    type Out<R> = InnerResponse<R>;
+
+    fn show<'world, R>(
+        self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let (world, ui) = ui.into_parts();
+        ui.scope(|ui| {
+            ui.style_mut().wrap_mode = Some(self.0);
+            let ui = WorldUi::new(world, ui);
+            f(ui)
+        })
+    }
+}
+
+/// [`Container`] that sets the item spacing of its contents, e.g. for compact
+/// toolbars.
+///
+/// The contents are drawn in a child [`Ui`] with its own style, so the spacing
+/// of the surrounding [`Ui`] is never modified, even if the closure panics.
+pub struct Spacing(pub Vec2);
+
+impl Container for Spacing {
+    type Ui = Ui;
+    type Out<R> = InnerResponse<R>;
+
+    fn show<'world, R>(
+        self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let (world, ui) = ui.into_parts();
+        ui.scope(|ui| {
+            ui.spacing_mut().item_spacing = self.0;
+            let ui = WorldUi::new(world, ui);
+            f(ui)
+        })
+    }
+}
+
+/// [`Container`] that indents its contents like [`Ui::indent`], optionally
+/// with a custom indent width instead of the style's default, e.g. for deeply
+/// nested trees.
+pub struct Indent<S> {
+    /// The salt for the id of the indented region.
+    pub id_salt: S,
+    /// The indent width, or [`None`] to use the style's default.
+    pub width: Option<f32>,
+}
+
+impl<S: Hash> Container for Indent<S> {
+    type Ui = Ui;
+    type Out<R> = InnerResponse<R>;
+
+    fn show<'world, R>(
+        self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let (world, ui) = ui.into_parts();
+        ui.scope(|ui| {
+            if let Some(width) = self.width {
+                ui.spacing_mut().indent = width;
+            }
+            ui.indent(self.id_salt, |ui| {
+                let ui = WorldUi::new(world, ui);
+                f(ui)
+            })
+            .inner
+        })
+    }
+}
+
 /// [`Container`] that groups [`Widget`]s together with [`Ui::group`].
 ///
 /// [`Widget`]: crate::widget::Widget
@@ -242,3 +466,277 @@ impl Container for Group {
         })
     }
 }
+
+/// Direction in which a [`ScrollArea`] can be scrolled. See
+/// [`WorldUi::scroll`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScrollDir {
+    /// Only horizontal scrolling.
+    Horizontal,
+    /// Only vertical scrolling.
+    Vertical,
+    /// Scrolling in both directions.
+    Both,
+}
+
+impl From<ScrollDir> for ScrollArea {
+    fn from(dir: ScrollDir) -> Self {
+        match dir {
+            ScrollDir::Horizontal => ScrollArea::horizontal(),
+            ScrollDir::Vertical => ScrollArea::vertical(),
+            ScrollDir::Both => ScrollArea::both(),
+        }
+    }
+}
+
+/// [`Container`] that renders a [`ScrollArea`] which can be scrolled
+/// programmatically, e.g. to follow the end of a log.
+///
+/// The resulting scroll offset is available through the `state.offset` field
+/// of the returned [`ScrollAreaOutput`].
+pub struct AutoScroll {
+    /// The scroll area to render.
+    pub area: ScrollArea,
+    /// The vertical offset to scroll to this frame, if any.
+    pub offset: Option<f32>,
+    /// Whether to keep the area scrolled to the bottom as its content grows.
+    /// This only applies while the user was already at the bottom, so manual
+    /// scrolling isn't overridden.
+    pub scroll_to_bottom: bool,
+}
+
+impl Default for AutoScroll {
+    fn default() -> Self {
+        AutoScroll {
+            area: ScrollArea::vertical(),
+            offset: None,
+            scroll_to_bottom: false,
+        }
+    }
+}
+
+impl Container for AutoScroll {
+    type Ui = Ui;
+    type Out<R> = ScrollAreaOutput<R>;
+
+    fn show<'world, R>(
+        self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let mut area = self.area.stick_to_bottom(self.scroll_to_bottom);
+        if let Some(offset) = self.offset {
+            area = area.vertical_scroll_offset(offset);
+        }
+        Container::show(area, ui, f)
+    }
+}
+
+/// [`Container`] that renders a tab bar above its contents. Clicking a tab
+/// selects it, and the left and right arrow keys switch tabs while the tab bar
+/// has keyboard focus.
+///
+/// See also [`WorldUi::tabs`], which passes the selected index to the closure.
+pub struct Tabs<'a> {
+    /// The id of the tab bar, used to keep keyboard focus when switching tabs.
+    pub id: Id,
+    /// The titles of the tabs.
+    pub tabs: &'a [&'a str],
+    /// The index of the selected tab, updated when another tab is selected.
+    pub selected: &'a mut usize,
+}
+
+impl Tabs<'_> {
+    /// Renders the tab bar and updates the selected index.
+    pub(crate) fn show_bar(&mut self, ui: &mut Ui) {
+        let selected = &mut *self.selected;
+        *selected = (*selected).min(self.tabs.len().saturating_sub(1));
+        ui.push_id(self.id, |ui| {
+            ui.horizontal(|ui| {
+                let mut ids = Vec::with_capacity(self.tabs.len());
+                let mut focused = false;
+                for (index, tab) in self.tabs.iter().enumerate() {
+                    let response = ui.selectable_label(*selected == index, *tab);
+                    if response.clicked() {
+                        *selected = index;
+                    }
+                    focused |= response.has_focus();
+                    ids.push(response.id);
+                }
+                if !focused {
+                    return;
+                }
+                let (left, right) = ui.input(|input| {
+                    (
+                        input.key_pressed(Key::ArrowLeft),
+                        input.key_pressed(Key::ArrowRight),
+                    )
+                });
+                let index = if left {
+                    selected.saturating_sub(1)
+                } else if right {
+                    (*selected + 1).min(ids.len() - 1)
+                } else {
+                    return;
+                };
+                *selected = index;
+                ui.memory_mut(|memory| memory.request_focus(ids[index]));
+            });
+        });
+    }
+}
+
+impl Container for Tabs<'_> {
+    type Ui = Ui;
+    type Out<R> = R;
+
+    fn show<'world, R>(
+        mut self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let (world, ui) = ui.into_parts();
+        self.show_bar(ui);
+        f(WorldUi::new(world, ui))
+    }
+}
+
+/// Direction in which a [`Split`] lays out its two regions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitDir {
+    /// The regions are placed side by side, left and right.
+    Horizontal,
+    /// The regions are stacked, top and bottom.
+    Vertical,
+}
+
+/// [`Container`] that splits the available space into two regions separated by
+/// a draggable divider. The closure receives a [`WorldUi`] for both regions.
+///
+/// `fraction` is the share of the space given to the first region, and is
+/// updated as the divider is dragged.
+pub struct Split<'a> {
+    /// The direction in which to split.
+    pub direction: SplitDir,
+    /// The share of the space given to the first region, between `0.0` and
+    /// `1.0`.
+    pub fraction: &'a mut f32,
+}
+
+impl Container for Split<'_> {
+    type Ui = [Ui; 2];
+    type Out<R> = R;
+
+    fn show<'world, R>(
+        self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let (world, ui) = ui.into_parts();
+        let rect = ui.available_rect_before_wrap();
+        let axis = match self.direction {
+            SplitDir::Horizontal => 0,
+            SplitDir::Vertical => 1,
+        };
+        let thickness = ui.spacing().item_spacing[axis].max(1.0);
+        let available = (rect.size()[axis] - thickness).max(0.0);
+
+        let divider_rect = |fraction: f32| {
+            let start = rect.min[axis] + available * fraction;
+            let mut divider = rect;
+            divider.min[axis] = start;
+            divider.max[axis] = start + thickness;
+            divider
+        };
+
+        let id = ui.auto_id_with("split_divider");
+        let response = ui.interact(divider_rect(*self.fraction), id, Sense::drag());
+        if response.dragged() && available > 0.0 {
+            *self.fraction += response.drag_delta()[axis] / available;
+        }
+        *self.fraction = self.fraction.clamp(0.0, 1.0);
+        if response.hovered() || response.dragged() {
+            ui.ctx().set_cursor_icon(match self.direction {
+                SplitDir::Horizontal => CursorIcon::ResizeHorizontal,
+                SplitDir::Vertical => CursorIcon::ResizeVertical,
+            });
+        }
+
+        let divider = divider_rect(*self.fraction);
+        let stroke = ui.style().interact(&response).bg_stroke;
+        match self.direction {
+            SplitDir::Horizontal => {
+                ui.painter()
+                    .vline(divider.center().x, divider.y_range(), stroke);
+            }
+            SplitDir::Vertical => {
+                ui.painter()
+                    .hline(divider.x_range(), divider.center().y, stroke);
+            }
+        }
+
+        let mut first = rect;
+        first.max[axis] = divider.min[axis];
+        let mut second = rect;
+        second.min[axis] = divider.max[axis];
+        let mut regions = [first, second].map(|region| {
+            let mut child = ui.new_child(
+                UiBuilder::new()
+                    .max_rect(region)
+                    .layout(Layout::top_down(Align::Min)),
+            );
+            child.shrink_clip_rect(region);
+            child
+        });
+
+        let out = f(WorldUi::new(world, &mut regions));
+        ui.advance_cursor_after_rect(rect);
+        out
+    }
+}
+
+/// [`Container`] that allocates a fixed-size region for custom painting, e.g.
+/// for charts and minimaps. Inside the closure, [`WorldUi::painter`] is clipped
+/// to the region and [`WorldUi::max_rect`] returns it.
+///
+/// See also [`WorldUi::canvas`].
+pub struct Canvas {
+    /// The size of the region to allocate.
+    pub size: Vec2,
+}
+
+impl Container for Canvas {
+    type Ui = Ui;
+    type Out<R> = R;
+
+    fn show<'world, R>(
+        self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let (world, ui) = ui.into_parts();
+        let (rect, _) = ui.allocate_exact_size(self.size, Sense::hover());
+        let mut canvas = ui.new_child(UiBuilder::new().max_rect(rect));
+        canvas.shrink_clip_rect(rect);
+        f(WorldUi::new(world, &mut canvas))
+    }
+}
+
+/// Shows a plot, giving the closure access to both the [`World`] (e.g. to
+/// fetch series data) and the [`PlotUi`](egui_plot::PlotUi).
+///
+/// [`World`]: bevy_ecs::world::World
+#[cfg(feature = "egui_plot")]
+impl Container for egui_plot::Plot<'_> {
+    type Ui = egui_plot::PlotUi;
+    type Out<R> = egui_plot::PlotResponse<R>;
+
+    fn show<'world, R>(
+        self,
+        ui: WorldUi<'world, '_>,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let (world, ui) = ui.into_parts();
+        self.show(ui, |plot_ui| f(WorldUi::new(world, plot_ui)))
+    }
+}
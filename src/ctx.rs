@@ -1,11 +1,45 @@
 //! Provides types and traits for rendering root containers in a given [`World`].
 
-use bevy_ecs::world::World;
-use bevy_egui::EguiContexts;
+use std::{
+    any::{type_name, Any, TypeId},
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    fmt,
+    num::NonZeroUsize,
+    sync::Arc,
+};
+
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    entity::Entity,
+    query::{QueryFilter, With},
+    system::{Query, ResMut, Resource},
+    world::World,
+};
+use bevy_egui::{
+    EguiContext, EguiContexts, EguiInput, EguiSettings, EguiUserTextures, RenderTargetSize,
+};
+use bevy_input::gamepad::{Gamepad, GamepadButton};
+#[cfg(feature = "persistence")]
+use bevy_log::warn;
 use bevy_log::warn_once;
-use egui::Context;
+use bevy_state::state::{State, States};
+use bevy_text::Font;
+use bevy_window::PrimaryWindow;
+use egui::{
+    collapsing_header::CollapsingState, menu, panel::Side, Area, CentralPanel, Color32, Context,
+    CursorIcon, Event, FontData, FontDefinitions, FontFamily, FullOutput, Id, InnerResponse, Key,
+    KeyboardShortcut, Memory, Modifiers, Order, RawInput, Rect, Response, Sense, SidePanel, Style,
+    TopBottomPanel, Ui, Visuals, WidgetText, Window,
+};
 
-use crate::{root::Root, ui::WorldUi};
+use crate::{
+    command::DeferredUi,
+    root::{ComponentWindow, Root, SidebarToggle},
+    ui::WorldUi,
+};
 
 /// Context for rendering root containers in a given [`World`].
 pub struct WorldCtx<'world> {
@@ -17,13 +51,44 @@ impl<'world> WorldCtx<'world> {
     /// Creates a new instance with the given [`World`] using the [`Context`]
     /// that corresponds to the primary window.
     pub fn new(world: &'world mut World) -> Option<Self> {
-        fn get_ctx(mut ctxs: EguiContexts) -> Option<Context> {
-            ctxs.try_ctx_mut().cloned()
+        Self::try_new(world)
+            .map_err(|err| warn_once!("No egui context found: {err}"))
+            .ok()
+    }
+
+    /// Like [`Self::new`], but returns the reason why the [`Context`] of the
+    /// primary window couldn't be found.
+    pub fn try_new(world: &'world mut World) -> Result<Self, CtxError> {
+        if !world.contains_resource::<EguiUserTextures>() {
+            return Err(CtxError::PluginNotAdded);
         }
-        let Ok(Some(ctx)) = world.run_system_cached(get_ctx) else {
-            warn_once!("No egui context found");
+        let mut windows = world.query_filtered::<Option<&mut EguiContext>, With<PrimaryWindow>>();
+        let ctx = match windows.get_single_mut(world) {
+            Ok(Some(mut ctx)) => ctx.get_mut().clone(),
+            Ok(None) => return Err(CtxError::NotInitialized),
+            Err(_) => return Err(CtxError::NoPrimaryWindow),
+        };
+        Ok(Self { world, ctx })
+    }
+
+    /// Creates a new instance with the given [`World`] and [`Context`], e.g. a
+    /// [`Context::default`] for headless tests of UI logic without a window.
+    ///
+    /// The caller is responsible for running the context's passes, e.g. with
+    /// [`Self::run`] or [`Self::begin_pass`] and [`Self::end_pass`].
+    pub fn from_context(world: &'world mut World, ctx: Context) -> Self {
+        Self { world, ctx }
+    }
+
+    /// Creates a new instance with the given [`World`] using the [`Context`]
+    /// of the given entity, e.g. a render-to-texture context for world-space
+    /// UI.
+    pub fn for_entity(world: &'world mut World, entity: Entity) -> Option<Self> {
+        let Some(mut ctx) = world.get_mut::<EguiContext>(entity) else {
+            warn_once!("No egui context found on {entity}");
             return None;
         };
+        let ctx = ctx.get_mut().clone();
         Some(Self { world, ctx })
     }
 
@@ -36,14 +101,864 @@ impl<'world> WorldCtx<'world> {
     ) -> Ro::Out<R> {
         root.show(self.world, &self.ctx, f)
     }
+
+    /// Like [`Self::show`], but if `disabled` is `true` the root's contents are
+    /// disabled, which dims them and makes them non-interactive, and the root
+    /// is made non-interactable with [`Root::interactable`], so that pointer
+    /// input passes through it to the game.
+    ///
+    /// Panels can't stop claiming the pointer, so
+    /// [`Context::wants_pointer_input`] and [`Context::is_pointer_over_area`]
+    /// are still `true` over a disabled panel. Gameplay systems gated on those
+    /// should also check `disabled` if panels are disabled this way.
+    pub fn show_disabled_if<Ro: Root<Ui = Ui>, R>(
+        &mut self,
+        disabled: bool,
+        root: Ro,
+        f: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> Ro::Out<R> {
+        let root = if disabled {
+            root.interactable(false)
+        } else {
+            root
+        };
+        self.show(root, |mut ui| {
+            if disabled {
+                ui.ui_mut().disable();
+            }
+            f(ui)
+        })
+    }
+
+    /// Like [`Self::show_disabled_if`], but the root's contents are disabled
+    /// while the [`State`] `S` is `state`, e.g. during a cutscene.
+    ///
+    /// As with [`Self::show_disabled_if`], disabled panels still claim the
+    /// pointer, so gameplay systems can use an `in_state(state)` run condition
+    /// rather than relying on [`Context::wants_pointer_input`].
+    pub fn show_disabled_in<S: States, Ro: Root<Ui = Ui>, R>(
+        &mut self,
+        state: S,
+        root: Ro,
+        f: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> Ro::Out<R> {
+        let disabled = self
+            .world
+            .get_resource::<State<S>>()
+            .is_some_and(|current| *current.get() == state);
+        self.show_disabled_if(disabled, root, f)
+    }
+
+    /// Shows a [`SidePanel`] whose width is initialized from and written back
+    /// to `width`, e.g. so that it can be saved to a settings file.
+    pub fn show_side_panel<R>(
+        &mut self,
+        panel: SidePanel,
+        width: &mut f32,
+        f: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        let response = self.show(panel.default_width(*width), f);
+        *width = response.response.rect.width();
+        response
+    }
+
+    /// Shows a menu bar in a [`TopBottomPanel`] at the top, followed by a
+    /// [`CentralPanel`] for the rest of the screen. The panels are shown in the
+    /// order egui requires, so the central panel doesn't overlap the menu bar.
+    ///
+    /// Other panels need to be shown before calling this.
+    pub fn app_shell<R>(
+        &mut self,
+        menu: impl FnOnce(WorldUi<'_, '_, Ui>),
+        central: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> R {
+        self.show(TopBottomPanel::top("evergreen_egui_menu_bar"), |ui| {
+            let (world, ui) = ui.into_parts();
+            menu::bar(ui, |ui| menu(WorldUi::new(world, ui)));
+        });
+        self.show(CentralPanel::default(), central).inner
+    }
+
+    /// Shows a collapsible [`SidePanel`] with a toggle button at the top. The
+    /// expanded state is read from and written to the resource `Res` through
+    /// the given accessor, and the closure receives it as well, e.g. to only
+    /// show icons while collapsed.
+    ///
+    /// The width is animated with a fixed width each frame, so the panel
+    /// doesn't jump while switching states. Returns [`None`] if the resource
+    /// doesn't exist.
+    pub fn show_sidebar<Res: Resource, R>(
+        &mut self,
+        sidebar: SidebarToggle,
+        expanded: impl Fn(&mut Res) -> &mut bool,
+        f: impl FnOnce(WorldUi<'_, '_, Ui>, bool) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let Some(mut resource) = self.world.get_resource_mut::<Res>() else {
+            warn_once!("Resource {} not found", type_name::<Res>());
+            return None;
+        };
+        let is_expanded = *expanded(resource.bypass_change_detection());
+
+        let target = if is_expanded {
+            sidebar.expanded_width
+        } else {
+            sidebar.collapsed_width
+        };
+        let width = self.ctx.animate_value_with_time(
+            sidebar.id.with("sidebar_width"),
+            target,
+            sidebar.animation_time,
+        );
+        let panel = SidePanel::new(sidebar.side, sidebar.id)
+            .resizable(false)
+            .exact_width(width);
+
+        let mut toggled = false;
+        let response = self.show(panel, |mut ui| {
+            let icon = match (sidebar.side, is_expanded) {
+                (Side::Left, true) | (Side::Right, false) => "⏴",
+                (Side::Left, false) | (Side::Right, true) => "⏵",
+            };
+            toggled = ui.ui_mut().button(icon).clicked();
+            f(ui, is_expanded)
+        });
+
+        if toggled {
+            if let Some(mut resource) = self.world.get_resource_mut::<Res>() {
+                let expanded = expanded(&mut resource);
+                *expanded = !*expanded;
+            }
+        }
+        Some(response)
+    }
+
+    /// Shows a [`TopBottomPanel`] whose height is initialized from and written
+    /// back to `height`, e.g. so that it can be saved to a settings file.
+    pub fn show_top_bottom_panel<R>(
+        &mut self,
+        panel: TopBottomPanel,
+        height: &mut f32,
+        f: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        let response = self.show(panel.default_height(*height), f);
+        *height = response.response.rect.height();
+        response
+    }
+
+    /// Shows a full-screen [`Area`] in front of all other UI with a dimmed
+    /// backdrop, e.g. for pause menus. The overlay captures all pointer input,
+    /// so [`Context::wants_pointer_input`] is `true` while it's shown.
+    pub fn show_overlay<R>(
+        &mut self,
+        f: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        self.show_overlay_with(Color32::from_black_alpha(160), f)
+    }
+
+    /// Like [`Self::show_overlay`], but with the given backdrop color.
+    pub fn show_overlay_with<R>(
+        &mut self,
+        backdrop: Color32,
+        f: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        let screen = self.ctx.screen_rect();
+        let area = Area::new(Id::new("evergreen_egui_overlay"))
+            .order(Order::Foreground)
+            .fixed_pos(screen.min)
+            .constrain(false)
+            .sense(Sense::click_and_drag());
+        self.show(area, |mut ui| {
+            ui.ui_mut().set_min_size(screen.size());
+            ui.painter().rect_filled(screen, 0.0, backdrop);
+            f(ui)
+        })
+    }
+
+    /// Shows the given [`Window`] constrained to the given rect, e.g. the
+    /// space left over by side panels, so it can't be dragged outside of it.
+    pub fn show_window_in<R>(
+        &mut self,
+        rect: Rect,
+        window: Window<'_>,
+        f: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> Option<InnerResponse<Option<R>>> {
+        self.show(window.constrain_to(rect), f)
+    }
+
+    /// Returns the rect of the whole screen in egui points, e.g. for placing an
+    /// [`Area`] in a corner.
+    pub fn screen_rect(&self) -> Rect {
+        self.ctx.screen_rect()
+    }
+
+    /// Returns the rect that is left over after all panels shown so far, e.g.
+    /// for [`Self::show_window_in`].
+    pub fn available_rect(&self) -> Rect {
+        self.ctx.available_rect()
+    }
+
+    /// Shows a [`Window`] with the given title whose id is derived from the
+    /// given [`Entity`]. Returns `false` if the user closed the window.
+    pub fn show_entity_window(
+        &mut self,
+        entity: Entity,
+        title: impl Into<WidgetText>,
+        f: impl FnOnce(WorldUi<'_, '_, Ui>),
+    ) -> bool {
+        self.show_entity_window_impl(entity, title, false, f)
+    }
+
+    fn show_entity_window_impl(
+        &mut self,
+        entity: Entity,
+        title: impl Into<WidgetText>,
+        close_on_escape: bool,
+        f: impl FnOnce(WorldUi<'_, '_, Ui>),
+    ) -> bool {
+        let mut open = true;
+        let window = Window::new(title).id(Id::new(entity)).open(&mut open);
+        let response = self.show(window, f);
+        if close_on_escape && response.is_some_and(|r| self.consume_escape_for(&r.response)) {
+            open = false;
+        }
+        open
+    }
+
+    /// Returns `true` if the window with the given [`Response`] is the topmost
+    /// window and Escape was pressed, consuming the key press. Only the topmost
+    /// window is closed this way, even if several windows check for Escape.
+    pub fn consume_escape_for(&mut self, window: &Response) -> bool {
+        let is_top =
+            self.ctx.memory(|m| m.areas().top_layer_id(Order::Middle)) == Some(window.layer_id);
+        is_top
+            && self
+                .ctx
+                .input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape))
+    }
+
+    /// Shows a [`Window`] for each entity with an open [`ComponentWindow`]
+    /// that matches the query filter `F`, and calls the given closure to fill
+    /// it. Closing a window updates its [`ComponentWindow::open`] field.
+    ///
+    /// Windows with [`ComponentWindow::close_on_escape`] set are closed when
+    /// Escape is pressed while they're the topmost window.
+    pub fn show_windows_for<F: QueryFilter>(
+        &mut self,
+        mut f: impl FnMut(WorldUi<'_, '_, Ui>, Entity),
+    ) {
+        let mut query = self.world.query_filtered::<(Entity, &ComponentWindow), F>();
+        let windows: Vec<(Entity, String, bool)> = query
+            .iter(self.world)
+            .filter(|(_, window)| window.open)
+            .map(|(entity, window)| (entity, window.title.clone(), window.close_on_escape))
+            .collect();
+        for (entity, title, close_on_escape) in windows {
+            if !self.show_entity_window_impl(entity, title, close_on_escape, |ui| f(ui, entity)) {
+                if let Some(mut window) = self.world.get_mut::<ComponentWindow>(entity) {
+                    window.open = false;
+                }
+            }
+        }
+    }
+
+    /// Consumes the given [`KeyboardShortcut`] from this context's input,
+    /// returning `true` if it was pressed this frame.
+    ///
+    /// Once consumed, the key press is removed from egui's input so that
+    /// widgets shown afterwards won't react to it. Bevy's own keyboard input is
+    /// left untouched; gameplay systems should still check
+    /// [`Context::wants_keyboard_input`] before reading keys.
+    pub fn consume_shortcut(&mut self, shortcut: &KeyboardShortcut) -> bool {
+        self.ctx.input_mut(|i| i.consume_shortcut(shortcut))
+    }
+
+    /// Returns the current [`Style`] of this context.
+    pub fn style(&self) -> Arc<Style> {
+        self.ctx.style()
+    }
+
+    /// Sets the [`Style`] of this context.
+    pub fn set_style(&self, style: impl Into<Arc<Style>>) {
+        self.ctx.set_style(style);
+    }
+
+    /// Sets the [`Visuals`] of this context, e.g. to switch between light and
+    /// dark mode.
+    pub fn set_visuals(&self, visuals: Visuals) {
+        self.ctx.set_visuals(visuals);
+    }
+
+    /// Adds a font with the given name to the given [`FontFamily`], taking
+    /// precedence over the family's existing fonts.
+    ///
+    /// Installed fonts are tracked in the [`InstalledFonts`] resource, which
+    /// is shared by all contexts, and each context remembers which generation
+    /// of it was installed last. The fonts of a context are only rebuilt if the
+    /// font set changed since, so it's fine to call this every frame. Fonts are
+    /// identified by their name and family, so if the font is already
+    /// installed, `data` is dropped without being looked at; to replace the
+    /// data of a font, [uninstall](Self::uninstall_font) it first. Returns
+    /// `true` if the fonts of this context were rebuilt.
+    ///
+    /// The fonts are rebuilt from [`FontDefinitions::default`], replacing any
+    /// fonts set on the context by other means. Install all custom fonts
+    /// through this method to keep them.
+    pub fn install_font(&mut self, name: &str, data: Vec<u8>, family: FontFamily) -> bool {
+        let mut installed = self.world.get_resource_or_init::<InstalledFonts>();
+        let unchanged = installed
+            .fonts
+            .get(name)
+            .is_some_and(|font| font.family == family);
+        if !unchanged {
+            installed.insert(
+                name,
+                InstalledFont {
+                    data: FontData::from_owned(data),
+                    family,
+                    source: None,
+                },
+            );
+        }
+        self.sync_fonts()
+    }
+
+    /// Like [`Self::install_font`], but uses the data of the given Bevy
+    /// [`Font`] asset. Returns `false` if the asset isn't loaded yet.
+    ///
+    /// Unlike with [`Self::install_font`], the font is replaced if the asset's
+    /// data changes, e.g. when it's reloaded. Changes are detected by the
+    /// identity of the asset's shared data, so the font bytes aren't compared
+    /// on every call.
+    pub fn install_font_asset(
+        &mut self,
+        name: &str,
+        font: &Handle<Font>,
+        family: FontFamily,
+    ) -> bool {
+        let Some(data) = self
+            .world
+            .get_resource::<Assets<Font>>()
+            .and_then(|fonts| fonts.get(font))
+            .map(|font| font.data.clone())
+        else {
+            return false;
+        };
+        let mut installed = self.world.get_resource_or_init::<InstalledFonts>();
+        let unchanged = installed.fonts.get(name).is_some_and(|font| {
+            font.family == family
+                && font
+                    .source
+                    .as_ref()
+                    .is_some_and(|source| Arc::ptr_eq(source, &data))
+        });
+        if !unchanged {
+            installed.insert(
+                name,
+                InstalledFont {
+                    data: FontData::from_owned(data.to_vec()),
+                    family,
+                    source: Some(data),
+                },
+            );
+        }
+        self.sync_fonts()
+    }
+
+    /// Removes the font with the given name installed with
+    /// [`Self::install_font`] or [`Self::install_font_asset`], and rebuilds the
+    /// fonts of this context without it. Other contexts are rebuilt the next
+    /// time a font is installed on them. Returns `false` if no font with that
+    /// name was installed.
+    pub fn uninstall_font(&mut self, name: &str) -> bool {
+        let removed = self
+            .world
+            .get_resource_mut::<InstalledFonts>()
+            .is_some_and(|mut installed| installed.remove(name));
+        if removed {
+            self.sync_fonts();
+        }
+        removed
+    }
+
+    /// Rebuilds the fonts of this context if the [`InstalledFonts`] changed
+    /// since they were last installed on it. Returns `true` if they were
+    /// rebuilt.
+    fn sync_fonts(&mut self) -> bool {
+        let installed = self.world.resource::<InstalledFonts>();
+        let generation = self
+            .ctx
+            .data(|data| data.get_temp::<u64>(installed_fonts_id()));
+        if generation == Some(installed.generation) {
+            return false;
+        }
+
+        let mut definitions = FontDefinitions::default();
+        for (name, font) in &installed.fonts {
+            definitions
+                .font_data
+                .insert(name.clone(), font.data.clone());
+            definitions
+                .families
+                .entry(font.family.clone())
+                .or_default()
+                .insert(0, name.clone());
+        }
+        self.ctx.set_fonts(definitions);
+        let generation = installed.generation;
+        self.ctx
+            .data_mut(|data| data.insert_temp(installed_fonts_id(), generation));
+        true
+    }
+
+    /// Clears egui's memory of collapsed states, window positions, and other
+    /// per-widget state. Options such as the current style are kept.
+    pub fn reset_memory(&self) {
+        self.ctx.memory_mut(|m| {
+            let options = std::mem::take(&mut m.options);
+            *m = Memory::default();
+            m.options = options;
+        });
+    }
+
+    /// [`Context::set_cursor_icon`], e.g. for a grab cursor while dragging.
+    /// The icon is reset at the start of each pass.
+    pub fn set_cursor_icon(&self, icon: CursorIcon) {
+        self.ctx.set_cursor_icon(icon);
+    }
+
+    /// Removes all [`Event::Paste`] events from this pass's input and returns
+    /// their text, or [`None`] if nothing was pasted. Call this before showing
+    /// the widget that would otherwise handle the paste, e.g. to validate or
+    /// transform the text before inserting it yourself.
+    pub fn take_pasted_text(&self) -> Option<String> {
+        self.ctx.input_mut(|input| {
+            let mut pasted: Option<String> = None;
+            input.events.retain(|event| match event {
+                Event::Paste(text) => {
+                    pasted.get_or_insert_with(String::new).push_str(text);
+                    false
+                }
+                _ => true,
+            });
+            pasted
+        })
+    }
+
+    /// Removes keyboard focus from whichever widget has it, e.g. when closing
+    /// a dialog.
+    pub fn clear_focus(&self) {
+        self.ctx.memory_mut(|m| {
+            if let Some(id) = m.focused() {
+                m.surrender_focus(id);
+            }
+        });
+    }
+
+    /// Returns the number of physical pixels per egui point of this context.
+    pub fn pixels_per_point(&self) -> f32 {
+        self.ctx.pixels_per_point()
+    }
+
+    /// Scales the UI of this context so that it has the given number of
+    /// physical pixels per point, e.g. from a UI scale setting.
+    ///
+    /// `bevy_egui` controls the pixels per point through [`EguiSettings`], so
+    /// the scale factor there is updated instead, and the change takes effect
+    /// in the next frame. Contexts not managed by `bevy_egui`, e.g. from
+    /// [`Self::from_context`], are scaled directly with
+    /// [`Context::set_pixels_per_point`].
+    pub fn set_pixels_per_point(&mut self, pixels_per_point: f32) {
+        let mut contexts = self
+            .world
+            .query::<(&mut EguiContext, &mut EguiSettings, &RenderTargetSize)>();
+        let (mut found, mut changed) = (false, false);
+        for (mut ctx, mut settings, size) in contexts.iter_mut(self.world) {
+            // Only reading the context, so it shouldn't be marked as changed.
+            if *ctx.bypass_change_detection().get_mut() == self.ctx && size.scale_factor > 0.0 {
+                found = true;
+                let scale_factor = pixels_per_point / size.scale_factor;
+                if settings.scale_factor != scale_factor {
+                    settings.scale_factor = scale_factor;
+                    changed = true;
+                }
+            }
+        }
+        if !found {
+            self.ctx.set_pixels_per_point(pixels_per_point);
+        } else if changed {
+            self.ctx.request_repaint();
+        }
+    }
+
+    /// Shows the UI recorded with [`UiCommands`] in the order it was applied,
+    /// e.g. in a context that [`show_deferred_ui`] doesn't cover. The recorded
+    /// UI is removed before it's shown, so a panic while showing it doesn't
+    /// leave stale UI behind for the next frame.
+    ///
+    /// [`UiCommands`]: crate::command::UiCommands
+    /// [`show_deferred_ui`]: crate::command::show_deferred_ui
+    pub fn show_deferred(&mut self) {
+        let commands = self
+            .world
+            .get_resource_mut::<DeferredUi>()
+            .map(|mut deferred| deferred.take())
+            .unwrap_or_default();
+        for command in commands {
+            command(self);
+        }
+    }
+
+    /// Queues the given raw input events, e.g. pointer moves, clicks and key
+    /// presses, to be fed into the next pass started with
+    /// [`Self::begin_pass`]. Meant for automated tests of UI logic together
+    /// with [`Self::from_context`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_ecs::{system::Resource, world::World};
+    /// use egui::{Button, CentralPanel, Context, Event, PointerButton, RawInput};
+    /// use evergreen_egui::prelude::*;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Clicks(u32);
+    ///
+    /// fn show(world: &mut World, ctx: &Context, events: Vec<Event>) -> egui::Rect {
+    ///     let mut ctx = WorldCtx::from_context(world, ctx.clone());
+    ///     ctx.simulate(events);
+    ///     ctx.begin_pass(RawInput::default());
+    ///     let rect = ctx.show(CentralPanel::default(), |mut ui| {
+    ///         let response = ui.ui_mut().add(Button::new("Click me"));
+    ///         if response.clicked() {
+    ///             ui.resource_mut::<Clicks>().0 += 1;
+    ///         }
+    ///         response.rect
+    ///     });
+    ///     ctx.end_pass();
+    ///     rect.inner
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Clicks>();
+    /// let ctx = Context::default();
+    ///
+    /// // Lay out the button once to find out where it is.
+    /// let center = show(&mut world, &ctx, Vec::new()).center();
+    /// let button = |pressed| Event::PointerButton {
+    ///     pos: center,
+    ///     button: PointerButton::Primary,
+    ///     pressed,
+    ///     modifiers: Default::default(),
+    /// };
+    /// show(&mut world, &ctx, vec![Event::PointerMoved(center), button(true)]);
+    /// show(&mut world, &ctx, vec![button(false)]);
+    ///
+    /// assert_eq!(world.resource::<Clicks>().0, 1);
+    /// ```
+    pub fn simulate(&mut self, events: Vec<Event>) {
+        self.ctx.data_mut(|data| {
+            data.get_temp_mut_or_default::<Vec<Event>>(simulated_events_id())
+                .extend(events);
+        });
+    }
+
+    /// [`Context::begin_pass`] with the events queued by [`Self::simulate`]
+    /// appended to the given input. Only needed for contexts whose passes are
+    /// not run by `bevy_egui`, e.g. ones created with [`Self::from_context`].
+    pub fn begin_pass(&mut self, mut raw_input: RawInput) {
+        self.append_simulated_events(&mut raw_input);
+        self.ctx.begin_pass(raw_input);
+    }
+
+    /// [`Context::end_pass`], returning what to paint and which platform
+    /// actions to take.
+    pub fn end_pass(&mut self) -> FullOutput {
+        self.ctx.end_pass()
+    }
+
+    /// Runs a single pass of this context with the given input, showing the
+    /// contents in a [`CentralPanel`] that covers the whole screen. Returns
+    /// the [`FullOutput`], e.g. the textures delta and shapes for a custom
+    /// rendering backend or a render-to-texture UI, along with the closure's
+    /// output.
+    ///
+    /// Only use this for contexts whose passes are not run by `bevy_egui`,
+    /// e.g. ones created with [`Self::from_context`] or whose
+    /// [`EguiSettings::run_manually`] is set.
+    pub fn run<R>(
+        &mut self,
+        raw_input: RawInput,
+        f: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> (FullOutput, R) {
+        self.begin_pass(raw_input);
+        let inner = self.show(CentralPanel::default(), f).inner;
+        (self.end_pass(), inner)
+    }
+
+    /// [`Context::run`] with [`World`] access, calling the given closure once
+    /// per pass. More than one pass is only run if multi-pass is enabled with
+    /// [`Self::enable_multipass`] and a pass requests to be discarded.
+    ///
+    /// `bevy_egui` runs exactly one pass per frame, so discard requests have
+    /// no effect on its contexts. To get multi-pass layout for a window, set
+    /// [`EguiSettings::run_manually`] and call this from your own system.
+    pub fn run_passes(
+        &mut self,
+        mut raw_input: RawInput,
+        mut f: impl FnMut(&mut WorldCtx<'world>),
+    ) -> FullOutput {
+        self.append_simulated_events(&mut raw_input);
+        let ctx = self.ctx.clone();
+        ctx.run(raw_input, |_| f(self))
+    }
+
+    /// Sets the maximum number of passes egui may run per frame, allowing
+    /// content-sized containers to lay themselves out again before being
+    /// shown, e.g. auto-sized windows on their first frame. A value of `1`
+    /// disables multi-pass. See [`Self::run_passes`].
+    pub fn enable_multipass(&self, max_passes: usize) {
+        let max_passes = NonZeroUsize::new(max_passes).unwrap_or(NonZeroUsize::MIN);
+        self.ctx
+            .options_mut(|options| options.max_passes = max_passes);
+    }
+
+    /// [`Context::request_discard`], asking for the output of this pass to be
+    /// discarded and another pass to be run instead. See
+    /// [`Self::enable_multipass`].
+    pub fn request_discard(&self, reason: impl Into<Cow<'static, str>>) {
+        self.ctx.request_discard(reason);
+    }
+
+    /// [`Context::will_discard`], i.e. whether the output of this pass will be
+    /// discarded, so expensive work can be skipped.
+    pub fn will_discard(&self) -> bool {
+        self.ctx.will_discard()
+    }
+
+    /// Appends the events queued by [`Self::simulate`] to the given input.
+    fn append_simulated_events(&self, raw_input: &mut RawInput) {
+        let events = self
+            .ctx
+            .data_mut(|data| data.remove_temp::<Vec<Event>>(simulated_events_id()));
+        raw_input.events.extend(events.unwrap_or_default());
+    }
+
+    /// Clears the [`UiScratch`] resource, e.g. at the start of each frame
+    /// before any UI is shown. See also [`clear_ui_scratch`].
+    pub fn begin_frame(&mut self) {
+        if let Some(mut scratch) = self.world.get_resource_mut::<UiScratch>() {
+            scratch.clear();
+        }
+    }
+
+    /// Forgets the positions and sizes of all windows and areas, so that they
+    /// are laid out from their defaults again.
+    pub fn reset_areas(&self) {
+        self.ctx.memory_mut(|m| m.reset_areas());
+    }
+
+    /// Serializes egui's memory, including window positions, panel sizes, and
+    /// collapsed states, e.g. to save an editor layout to a file.
+    #[cfg(feature = "persistence")]
+    pub fn dump_memory(&self) -> Vec<u8> {
+        self.ctx
+            .memory(ron::to_string)
+            .map(String::into_bytes)
+            .unwrap_or_else(|err| {
+                warn!("Failed to serialize egui memory: {err}");
+                Vec::new()
+            })
+    }
+
+    /// Replaces egui's memory with one previously serialized with
+    /// [`Self::dump_memory`].
+    #[cfg(feature = "persistence")]
+    pub fn load_memory(&self, bytes: &[u8]) -> Result<(), ron::error::SpannedError> {
+        let memory: Memory = ron::de::from_bytes(bytes)?;
+        self.ctx.memory_mut(|m| *m = memory);
+        Ok(())
+    }
+
+    /// Forgets the collapsed state of the [`Window`] with the given id.
+    ///
+    /// egui doesn't support forgetting the position of a single window; use
+    /// [`Self::reset_areas`] to reset the positions of all windows.
+    ///
+    /// [`Window`]: egui::Window
+    pub fn forget_window(&self, id: Id) {
+        if let Some(state) = CollapsingState::load(&self.ctx, id.with("collapsing")) {
+            state.remove(&self.ctx);
+        }
+    }
 }
 
+/// [`Resource`] for per-frame UI state, e.g. values accumulated while drawing
+/// and read after all UI is shown. Values are stored by type, and are removed
+/// by [`WorldCtx::begin_frame`] or the [`clear_ui_scratch`] system.
+#[derive(Resource, Default)]
+pub struct UiScratch {
+    values: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl UiScratch {
+    /// Returns the value of type `T`, inserting its default if it doesn't
+    /// exist yet.
+    pub fn get_or_default<T: Default + Send + Sync + 'static>(&mut self) -> &mut T {
+        self.values
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(T::default()))
+            .downcast_mut()
+            .expect("value should be stored under its own type id")
+    }
+
+    /// Returns the value of type `T`, if it exists.
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.values.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+/// System that clears the [`UiScratch`] resource, e.g. in the `First`
+/// schedule.
+pub fn clear_ui_scratch(mut scratch: ResMut<UiScratch>) {
+    scratch.clear();
+}
+
+/// System that lets gamepads navigate the UI of the primary window by
+/// injecting key presses into egui's input. The D-pad moves the keyboard
+/// focus, the south button (e.g. A) activates the focused widget, and the east
+/// button (e.g. B) clears the focus.
+///
+/// If no widget is focused yet, the D-pad focuses the first widget instead.
+/// Needs to run after egui processed its input, but before it begins the
+/// pass:
+///
+/// ```ignore
+/// app.add_systems(
+///     PreUpdate,
+///     gamepad_navigation
+///         .after(EguiSet::ProcessInput)
+///         .before(EguiSet::BeginPass),
+/// );
+/// ```
+pub fn gamepad_navigation(
+    gamepads: Query<&Gamepad>,
+    mut contexts: Query<(&mut EguiContext, &mut EguiInput), With<PrimaryWindow>>,
+) {
+    let Ok((mut ctx, mut input)) = contexts.get_single_mut() else {
+        return;
+    };
+    let has_focus = ctx.get_mut().memory(|m| m.focused().is_some());
+    for gamepad in &gamepads {
+        for (button, key) in [
+            (GamepadButton::DPadUp, Key::ArrowUp),
+            (GamepadButton::DPadDown, Key::ArrowDown),
+            (GamepadButton::DPadLeft, Key::ArrowLeft),
+            (GamepadButton::DPadRight, Key::ArrowRight),
+            (GamepadButton::South, Key::Enter),
+            (GamepadButton::East, Key::Escape),
+        ] {
+            if !gamepad.just_pressed(button) {
+                continue;
+            }
+            let is_dpad = !matches!(button, GamepadButton::South | GamepadButton::East);
+            let key = if is_dpad && !has_focus { Key::Tab } else { key };
+            for pressed in [true, false] {
+                input.0.events.push(Event::Key {
+                    key,
+                    physical_key: None,
+                    pressed,
+                    repeat: false,
+                    modifiers: Modifiers::NONE,
+                });
+            }
+        }
+    }
+}
+
+/// [`Resource`] tracking the fonts installed with [`WorldCtx::install_font`].
+#[derive(Resource, Default)]
+pub struct InstalledFonts {
+    fonts: BTreeMap<String, InstalledFont>,
+    /// Incremented whenever a font is added, replaced or removed.
+    generation: u64,
+}
+
+struct InstalledFont {
+    data: FontData,
+    family: FontFamily,
+    /// The asset data the font was installed from, if any.
+    source: Option<Arc<Vec<u8>>>,
+}
+
+impl InstalledFonts {
+    fn insert(&mut self, name: &str, font: InstalledFont) {
+        self.fonts.insert(name.to_owned(), font);
+        self.generation += 1;
+    }
+
+    fn remove(&mut self, name: &str) -> bool {
+        let removed = self.fonts.remove(name).is_some();
+        if removed {
+            self.generation += 1;
+        }
+        removed
+    }
+
+    /// Returns the names of the installed fonts.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.fonts.keys().map(String::as_str)
+    }
+}
+
+/// Error returned when a [`WorldCtx`] can't be created.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CtxError {
+    /// The [`EguiPlugin`] hasn't been added to the app.
+    ///
+    /// [`EguiPlugin`]: bevy_egui::EguiPlugin
+    PluginNotAdded,
+    /// There is no primary window.
+    NoPrimaryWindow,
+    /// The primary window's egui context hasn't been initialized yet, e.g.
+    /// because the window was created this frame.
+    NotInitialized,
+}
+
+impl fmt::Display for CtxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CtxError::PluginNotAdded => write!(f, "the egui plugin hasn't been added"),
+            CtxError::NoPrimaryWindow => write!(f, "there is no primary window"),
+            CtxError::NotInitialized => {
+                write!(f, "the primary window's egui context isn't initialized yet")
+            }
+        }
+    }
+}
+
+impl Error for CtxError {}
+
 /// [`World`] extension trait for fetching [`WorldCtx`] instances used to render
 /// root containers.
 pub trait WorldCtxExt {
     /// Tries to create a [`WorldCtx`] instance for the given [`World`]
     /// targeting the primary window.
     fn try_ctx_mut(&mut self) -> Option<WorldCtx<'_>>;
+
+    /// Tries to create a [`WorldCtx`] instance for the given [`World`]
+    /// targeting the egui context of the given entity, which doesn't have to
+    /// be a window.
+    fn try_ctx_for_entity_mut(&mut self, entity: Entity) -> Option<WorldCtx<'_>>;
+
+    /// Calls the given closure with a [`WorldCtx`] for each egui context in the
+    /// given [`World`], e.g. to render the same UI into multiple windows.
+    /// Contexts without an active render target are skipped.
+    fn for_each_ctx(&mut self, f: impl FnMut(WorldCtx<'_>));
 }
 
 impl WorldCtxExt for World {
@@ -57,4 +972,40 @@ impl WorldCtxExt for World {
             .flatten()
             .map(|ctx| WorldCtx { world: self, ctx })
     }
+
+    fn try_ctx_for_entity_mut(&mut self, entity: Entity) -> Option<WorldCtx<'_>> {
+        let ctx = self.get_mut::<EguiContext>(entity)?.get_mut().clone();
+        Some(WorldCtx { world: self, ctx })
+    }
+
+    fn for_each_ctx(&mut self, mut f: impl FnMut(WorldCtx<'_>)) {
+        let mut query = self.query::<(&mut EguiContext, Option<&RenderTargetSize>)>();
+        let ctxs: Vec<Context> = query
+            .iter_mut(self)
+            .filter_map(|(mut ctx, size)| {
+                if size
+                    .is_some_and(|size| size.physical_width <= 0.0 || size.physical_height <= 0.0)
+                {
+                    warn_once!("Skipping egui context without an active render target");
+                    return None;
+                }
+                Some(ctx.get_mut().clone())
+            })
+            .collect();
+        for ctx in ctxs {
+            f(WorldCtx { world: self, ctx });
+        }
+    }
+}
+
+/// The [`Id`] under which [`WorldCtx::simulate`] queues events in the
+/// [`Context`]'s temporary data.
+fn simulated_events_id() -> Id {
+    Id::new("evergreen_egui::simulated_events")
+}
+
+/// The [`Id`] under which a [`Context`]'s temporary data stores the generation
+/// of the [`InstalledFonts`] installed on it.
+fn installed_fonts_id() -> Id {
+    Id::new("evergreen_egui::installed_fonts")
 }
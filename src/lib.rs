@@ -3,9 +3,10 @@
 
 #![warn(missing_docs)]
 
-// pub mod command;
+pub mod command;
 pub mod container;
 pub mod ctx;
+pub mod responder;
 pub mod root;
 pub mod ui;
 pub mod widget;
@@ -13,9 +14,10 @@ pub mod widget;
 pub mod prelude {
     //! Commonly used traits and types.
 
-    // pub use crate::command::*;
+    pub use crate::command::*;
     pub use crate::container::*;
     pub use crate::ctx::*;
+    pub use crate::responder::*;
     pub use crate::root::*;
     pub use crate::ui::*;
     pub use crate::widget::*;
@@ -0,0 +1,327 @@
+//! Responders for reacting to widget [`Response`]s with [`World`] access.
+
+use std::{any::type_name, marker::PhantomData};
+
+#[cfg(feature = "audio")]
+use bevy_asset::Handle;
+#[cfg(feature = "audio")]
+use bevy_audio::{AudioPlayer, AudioSource, PlaybackSettings};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    system::{In, IntoSystem, Resource},
+    world::World,
+};
+use bevy_log::warn_once;
+use bevy_state::state::{FreelyMutableState, NextState};
+use egui::{Response, Vec2};
+
+/// Trait for types that can react to the [`Response`] of a widget.
+pub trait Responder {
+    /// Reacts to the given [`Response`] with access to the [`World`].
+    fn respond(self, world: &mut World, response: &Response);
+}
+
+/// Conversion trait to turn something into a [`Responder`].
+pub trait IntoResponder<M> {
+    /// The type of [`Responder`] that this conversion produces.
+    type Responder: Responder;
+
+    /// Converts this value into a [`Responder`].
+    fn into_responder(self) -> Self::Responder;
+}
+
+impl<R: Responder> IntoResponder<()> for R {
+    type Responder = R;
+
+    fn into_responder(self) -> Self::Responder {
+        self
+    }
+}
+
+/// Extension trait with combinators for anything that can be turned into a
+/// [`Responder`].
+pub trait ResponderExt<M>: IntoResponder<M> + Sized {
+    /// Only calls this responder if the given predicate returns `true` for the
+    /// [`Response`], e.g. [`Response::clicked`].
+    fn filter(self, predicate: fn(&Response) -> bool) -> Filter<Self::Responder> {
+        Filter {
+            responder: self.into_responder(),
+            predicate,
+        }
+    }
+
+    /// Calls this responder, followed by the given one.
+    fn then<R: IntoResponder<RM>, RM>(self, other: R) -> Then<Self::Responder, R::Responder> {
+        Then(self.into_responder(), other.into_responder())
+    }
+}
+
+impl<R: IntoResponder<M>, M> ResponderExt<M> for R {}
+
+/// [`Responder`] that only calls the inner responder if a predicate returns
+/// `true`. See [`ResponderExt::filter`].
+pub struct Filter<R> {
+    responder: R,
+    predicate: fn(&Response) -> bool,
+}
+
+impl<R: Responder> Responder for Filter<R> {
+    fn respond(self, world: &mut World, response: &Response) {
+        if (self.predicate)(response) {
+            self.responder.respond(world, response);
+        }
+    }
+}
+
+/// [`Responder`] that calls two responders in order. See
+/// [`ResponderExt::then`].
+pub struct Then<A, B>(A, B);
+
+impl<A: Responder, B: Responder> Responder for Then<A, B> {
+    fn respond(self, world: &mut World, response: &Response) {
+        self.0.respond(world, response);
+        self.1.respond(world, response);
+    }
+}
+
+/// [`Responder`] that runs a cached system with the [`Response`] as input.
+pub struct SystemResponder<S, M> {
+    system: S,
+    marker: PhantomData<fn() -> M>,
+}
+
+impl<S, M> Responder for SystemResponder<S, M>
+where
+    S: IntoSystem<In<Response>, (), M> + 'static,
+{
+    fn respond(self, world: &mut World, response: &Response) {
+        if let Err(err) = world.run_system_cached_with(self.system, response.clone()) {
+            warn_once!("Failed to run responder system: {err}");
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct SystemResponderMarker;
+
+impl<S, M> IntoResponder<(SystemResponderMarker, M)> for S
+where
+    S: IntoSystem<In<Response>, (), M> + 'static,
+{
+    type Responder = SystemResponder<S, M>;
+
+    fn into_responder(self) -> Self::Responder {
+        SystemResponder {
+            system: self,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// [`Responder`] that runs a cached system with the [`Response`] and an extra
+/// value as input, e.g. the [`Entity`] of a list row.
+///
+/// Created from a `(system, value)` tuple, where the system takes
+/// `In<(Response, T)>`.
+pub struct SystemWithInputResponder<S, T, M> {
+    system: S,
+    input: T,
+    marker: PhantomData<fn() -> M>,
+}
+
+impl<S, T, M> Responder for SystemWithInputResponder<S, T, M>
+where
+    S: IntoSystem<In<(Response, T)>, (), M> + 'static,
+    T: Send + 'static,
+{
+    fn respond(self, world: &mut World, response: &Response) {
+        let input = (response.clone(), self.input);
+        if let Err(err) = world.run_system_cached_with(self.system, input) {
+            warn_once!("Failed to run responder system: {err}");
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct SystemWithInputResponderMarker;
+
+impl<S, T, M> IntoResponder<(SystemWithInputResponderMarker, M)> for (S, T)
+where
+    S: IntoSystem<In<(Response, T)>, (), M> + 'static,
+    T: Send + 'static,
+{
+    type Responder = SystemWithInputResponder<S, T, M>;
+
+    fn into_responder(self) -> Self::Responder {
+        SystemWithInputResponder {
+            system: self.0,
+            input: self.1,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// [`Responder`] that mutates the [`Resource`] `R` with the given closure.
+///
+/// If the resource doesn't exist, a warning is logged and the closure isn't
+/// called.
+///
+/// This is created with [`SetResource::new`] rather than being a tuple struct,
+/// as only the bounds on the constructor let Rust infer the type of the
+/// closure's [`Response`] argument.
+///
+/// # Example
+///
+/// ```
+/// use bevy_ecs::system::Resource;
+/// use egui::Button;
+/// use evergreen_egui::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Settings {
+///     vsync: bool,
+/// }
+///
+/// fn settings(mut ui: WorldUi) {
+///     ui.add_with(
+///         Button::new("Toggle VSync"),
+///         SetResource::new(|settings: &mut Settings, response| {
+///             if response.clicked() {
+///                 settings.vsync = !settings.vsync;
+///             }
+///         }),
+///     );
+/// }
+/// ```
+pub struct SetResource<R, F> {
+    f: F,
+    marker: PhantomData<fn(&mut R)>,
+}
+
+impl<R, F> SetResource<R, F>
+where
+    R: Resource,
+    F: FnOnce(&mut R, &Response),
+{
+    /// Creates a new instance that calls the given closure with the resource
+    /// and the widget's [`Response`].
+    pub fn new(f: F) -> Self {
+        SetResource {
+            f,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<R, F> Responder for SetResource<R, F>
+where
+    R: Resource,
+    F: FnOnce(&mut R, &Response),
+{
+    fn respond(self, world: &mut World, response: &Response) {
+        let Some(mut resource) = world.get_resource_mut::<R>() else {
+            warn_once!("Resource {} not found", type_name::<R>());
+            return;
+        };
+        (self.f)(&mut resource, response);
+    }
+}
+
+/// [`Responder`] that switches to the given [`States`] when the widget is
+/// clicked, e.g. for menu navigation.
+///
+/// If the state doesn't exist, a warning is logged instead.
+///
+/// [`States`]: bevy_state::state::States
+pub struct SetState<S: FreelyMutableState>(pub S);
+
+impl<S: FreelyMutableState> Responder for SetState<S> {
+    fn respond(self, world: &mut World, response: &Response) {
+        if !response.clicked() {
+            return;
+        }
+        let Some(mut next) = world.get_resource_mut::<NextState<S>>() else {
+            warn_once!("Resource {} not found", type_name::<NextState<S>>());
+            return;
+        };
+        next.set(self.0);
+    }
+}
+
+/// [`Responder`] that applies the drag delta of a widget to the component `C`
+/// of an entity, e.g. to move a transform by dragging a handle.
+///
+/// The widget needs to sense drags, e.g. by adding it with
+/// [`WorldUi::drag_delta`]. Nothing happens if the delta is zero.
+///
+/// [`WorldUi::drag_delta`]: crate::ui::WorldUi::drag_delta
+pub struct DragResponder<C, F> {
+    entity: Entity,
+    f: F,
+    marker: PhantomData<fn(&mut C)>,
+}
+
+impl<C, F> DragResponder<C, F>
+where
+    C: Component,
+    F: FnOnce(&mut C, Vec2),
+{
+    /// Creates a new instance that calls the given closure with the component
+    /// of the given entity and the drag delta.
+    pub fn new(entity: Entity, f: F) -> Self {
+        DragResponder {
+            entity,
+            f,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C, F> Responder for DragResponder<C, F>
+where
+    C: Component,
+    F: FnOnce(&mut C, Vec2),
+{
+    fn respond(self, world: &mut World, response: &Response) {
+        let delta = response.drag_delta();
+        if delta == Vec2::ZERO {
+            return;
+        }
+        let Some(mut component) = world.get_mut::<C>(self.entity) else {
+            warn_once!(
+                "Component {} not found on {}",
+                type_name::<C>(),
+                self.entity
+            );
+            return;
+        };
+        (self.f)(&mut component, delta);
+    }
+}
+
+/// [`Responder`] that plays the given sound when the widget is clicked.
+///
+/// The sound is played at most once per click, even if egui runs multiple
+/// passes in the same frame.
+#[cfg(feature = "audio")]
+pub struct SoundOnClick(pub Handle<AudioSource>);
+
+#[cfg(feature = "audio")]
+impl Responder for SoundOnClick {
+    fn respond(self, world: &mut World, response: &Response) {
+        if !response.clicked() {
+            return;
+        }
+        let time = response.ctx.input(|input| input.time);
+        let id = response.id.with("evergreen_egui_sound_on_click");
+        let already_played = response.ctx.data_mut(|data| {
+            let last = data.get_temp::<f64>(id);
+            data.insert_temp(id, time);
+            last == Some(time)
+        });
+        if !already_played {
+            world.spawn((AudioPlayer(self.0), PlaybackSettings::DESPAWN));
+        }
+    }
+}
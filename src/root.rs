@@ -1,7 +1,10 @@
 //! Types and traits for creating root containers with which to build UIs.
 
-use bevy_ecs::world::World;
-use egui::{Area, CentralPanel, Context, InnerResponse, SidePanel, TopBottomPanel, Ui, Window};
+use bevy_ecs::{component::Component, world::World};
+use egui::{
+    panel::Side, Area, CentralPanel, Context, Id, InnerResponse, SidePanel, TopBottomPanel, Ui,
+    Window,
+};
 
 use crate::ui::WorldUi;
 
@@ -22,6 +25,18 @@ pub trait Root {
         ctx: &Context,
         f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
     ) -> Self::Out<R>;
+
+    /// Makes this root container stop claiming the pointer if `interactable`
+    /// is `false`, so that pointer input passes through it, e.g. to the game.
+    ///
+    /// Only [`Window`] and [`Area`] support this. Panels always claim the
+    /// pointer, so by default this returns the root unchanged.
+    fn interactable(self, _interactable: bool) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
 }
 
 impl Root for CentralPanel {
@@ -90,6 +105,10 @@ impl Root for Window<'_> {
             f(ui)
         })
     }
+
+    fn interactable(self, interactable: bool) -> Self {
+        Window::interactable(self, interactable)
+    }
 }
 
 impl Root for Area {
@@ -107,4 +126,151 @@ impl Root for Area {
             f(ui)
         })
     }
+
+    fn interactable(self, interactable: bool) -> Self {
+        Area::interactable(self, interactable)
+    }
+}
+
+/// [`Root`] built from a closure, for showing third-party egui containers that
+/// don't implement [`Root`] themselves.
+///
+/// The closure is called with the [`Context`] and a callback that renders the
+/// contents into a given [`Ui`]. If the callback is never called (e.g. because
+/// the container is collapsed), the contents' output is [`None`].
+///
+/// # Example
+///
+/// ```
+/// use egui::{CentralPanel, Context, Ui};
+/// use evergreen_egui::prelude::*;
+///
+/// // A third-party container that only knows how to show plain egui content.
+/// struct DockArea;
+///
+/// impl DockArea {
+///     fn show(self, ctx: &Context, add_contents: impl FnOnce(&mut Ui)) {
+///         CentralPanel::default().show(ctx, add_contents);
+///     }
+/// }
+///
+/// fn render(ctx: &mut WorldCtx) {
+///     let dock = FromFnRoot::new(|ctx, add_contents| DockArea.show(ctx, add_contents));
+///     ctx.show(dock, |mut ui| {
+///         ui.ui_mut().label("Docked!");
+///     });
+/// }
+/// ```
+pub struct FromFnRoot<F>(F);
+
+impl<F, O> FromFnRoot<F>
+where
+    F: FnOnce(&Context, &mut dyn FnMut(&mut Ui)) -> O,
+{
+    /// Creates a new instance from the given closure.
+    pub fn new(f: F) -> Self {
+        FromFnRoot(f)
+    }
+}
+
+impl<F, O> Root for FromFnRoot<F>
+where
+    F: FnOnce(&Context, &mut dyn FnMut(&mut Ui)) -> O,
+{
+    type Ui = Ui;
+    type Out<R> = (O, Option<R>);
+
+    fn show<'world, R>(
+        self,
+        world: &'world mut World,
+        ctx: &Context,
+        f: impl FnOnce(WorldUi<'world, '_, Self::Ui>) -> R,
+    ) -> Self::Out<R> {
+        let mut contents = Some((world, f));
+        let mut inner = None;
+        let out = (self.0)(ctx, &mut |ui| {
+            if let Some((world, f)) = contents.take() {
+                let ui = WorldUi::new(world, ui);
+                inner = Some(f(ui));
+            }
+        });
+        (out, inner)
+    }
+}
+
+/// Component for entities that should have a [`Window`] shown for them, e.g.
+/// one inspector per selected entity. See [`WorldCtx::show_windows_for`].
+///
+/// [`WorldCtx::show_windows_for`]: crate::ctx::WorldCtx::show_windows_for
+#[derive(Component, Clone, Debug)]
+pub struct ComponentWindow {
+    /// The title of the window.
+    pub title: String,
+    /// Whether the window is open. Set to `false` when the user closes it.
+    pub open: bool,
+    /// Whether the window is closed when Escape is pressed while it's the
+    /// topmost window.
+    pub close_on_escape: bool,
+}
+
+impl ComponentWindow {
+    /// Creates a new, open window with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        ComponentWindow {
+            title: title.into(),
+            open: true,
+            close_on_escape: false,
+        }
+    }
+
+    /// Makes the window close when Escape is pressed while it's the topmost
+    /// window.
+    pub fn close_on_escape(mut self) -> Self {
+        self.close_on_escape = true;
+        self
+    }
+}
+
+/// Configuration of a collapsible [`SidePanel`] whose width animates between a
+/// collapsed and an expanded state. See [`WorldCtx::show_sidebar`].
+///
+/// [`WorldCtx::show_sidebar`]: crate::ctx::WorldCtx::show_sidebar
+#[derive(Clone, Debug)]
+pub struct SidebarToggle {
+    /// The id of the panel.
+    pub id: Id,
+    /// The side of the screen the panel is on.
+    pub side: Side,
+    /// The width of the panel when collapsed, e.g. enough to fit icons.
+    pub collapsed_width: f32,
+    /// The width of the panel when expanded.
+    pub expanded_width: f32,
+    /// The duration of the animation in seconds.
+    pub animation_time: f32,
+}
+
+impl SidebarToggle {
+    /// Creates a new sidebar on the left side of the screen.
+    pub fn new(id: impl Into<Id>) -> Self {
+        SidebarToggle {
+            id: id.into(),
+            side: Side::Left,
+            collapsed_width: 32.0,
+            expanded_width: 200.0,
+            animation_time: 0.2,
+        }
+    }
+
+    /// Places the sidebar on the given side of the screen.
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Sets the widths of the panel when collapsed and expanded.
+    pub fn widths(mut self, collapsed: f32, expanded: f32) -> Self {
+        self.collapsed_width = collapsed;
+        self.expanded_width = expanded;
+        self
+    }
 }
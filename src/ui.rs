@@ -1,22 +1,51 @@
 //! Provides types and traits for rendering UI elements inside a [`World`].
 
 use std::{
+    any::{type_name, Any},
+    borrow::Cow,
+    error::Error,
+    fmt,
     hash::Hash,
-    ops::{Deref, DerefMut, IndexMut},
+    ops::{Deref, DerefMut, IndexMut, RangeInclusive},
+    sync::Arc,
 };
 
+use bevy_asset::Handle;
 use bevy_ecs::{
-    system::{IntoSystem, RegisteredSystemError, System, SystemInput},
+    change_detection::{DetectChangesMut, Mut},
+    entity::Entity,
+    event::{Event, Events},
+    query::{QueryData, QueryFilter},
+    system::{IntoSystem, Query, RegisteredSystemError, Resource, System, SystemInput},
     world::World,
 };
-use egui::{CollapsingResponse, InnerResponse, Ui, UiBuilder, WidgetText};
+use bevy_egui::EguiUserTextures;
+use bevy_image::Image;
+use bevy_log::{warn, warn_once};
+use bevy_math::{EulerRot, Quat, Vec3};
+use egui::{
+    collapsing_header::CollapsingState, emath::Numeric, scroll_area::ScrollAreaOutput,
+    text::LayoutJob, Align, Checkbox, CollapsingHeader, CollapsingResponse, ComboBox, Context,
+    CursorIcon, DragValue, EventFilter, FontSelection, Frame, Id, ImageButton, InnerResponse, Key,
+    Label, LayerId, Layout, Modifiers, Painter, ProgressBar, Rect, Response, RichText, ScrollArea,
+    SelectableLabel, Sense, Separator, Shape, Spinner, TextEdit, TextureId, Ui, UiBuilder, Vec2,
+    WidgetText,
+};
 
 use crate::{
+    container::{Canvas, Columns, Enabled, ScrollDir, Spacing, Tabs, Visible},
     prelude::Container,
-    widget::{Draw, IntoWidget, Widget},
+    responder::{IntoResponder, Responder},
+    widget::{ButtonVariant, Draw, IntoWidget, StyledButton, TransferList, TransferLists, Widget},
 };
 
 /// Context for rendering UI elements inside a [`World`].
+///
+/// Layout wrappers such as [`Self::group`], [`Self::scope`] and
+/// [`Self::indent`] return an [`InnerResponse`] whose `response` covers the
+/// whole region of their contents, so hover text or context menus can be
+/// attached to it uniformly. The region only senses hovering by default; use
+/// [`Self::scope_builder`] with [`UiBuilder::sense`] for clicks.
 pub struct WorldUi<'world, 'ui, U: ?Sized = Ui> {
     world: &'world mut World,
     ui: &'ui mut U,
@@ -74,6 +103,74 @@ impl<'world, 'ui, U: ?Sized> WorldUi<'world, 'ui, U> {
         }
     }
 
+    /// Returns the egui [`TextureId`] of the given [`Image`], registering it
+    /// with `bevy_egui` if needed. Returns [`None`] if `bevy_egui`'s user
+    /// textures aren't available.
+    pub fn texture_id(&mut self, image: &Handle<Image>) -> Option<TextureId> {
+        let Some(mut textures) = self.world.get_resource_mut::<EguiUserTextures>() else {
+            warn_once!("No egui user textures found");
+            return None;
+        };
+        Some(textures.add_image(image.clone_weak()))
+    }
+
+    /// Calls the given closure with a [`WorldUi`] that renders into the same
+    /// [`Ui`] instance but reads from and writes to the given [`World`]
+    /// instead.
+    pub fn with_world<R>(
+        &mut self,
+        world: &mut World,
+        f: impl FnOnce(WorldUi<'_, '_, U>) -> R,
+    ) -> R {
+        f(WorldUi { world, ui: self.ui })
+    }
+
+    /// Calls the given closure with a [`WorldUi`] backed by the [`ScratchWorld`]
+    /// resource, initializing it if it doesn't exist yet.
+    ///
+    /// The main world is inaccessible for the duration of the closure, so
+    /// widgets drawn inside can't affect it. Any data they need must be copied
+    /// into the scratch world beforehand.
+    pub fn with_scratch_world<R>(&mut self, f: impl FnOnce(WorldUi<'_, '_, U>) -> R) -> R {
+        self.world.init_resource::<ScratchWorld>();
+        let ui = &mut *self.ui;
+        self.world
+            .resource_scope(|_, mut scratch: Mut<ScratchWorld>| {
+                f(WorldUi {
+                    world: &mut scratch.0,
+                    ui,
+                })
+            })
+    }
+
+    /// Returns the [`Resource`] `R`, inserting its default value first if it
+    /// doesn't exist yet, e.g. for UI state that is created on first use.
+    pub fn resource_or<R: Resource + Default>(&mut self) -> Mut<'_, R> {
+        self.world.get_resource_or_init::<R>()
+    }
+
+    /// Calls the given closure with the [`Resource`] `R` and a [`WorldUi`] for
+    /// the rest of the [`World`]. If the resource doesn't exist, the closure
+    /// isn't called and [`None`] is returned instead, so UI shown before the
+    /// resource is inserted can render a fallback rather than panicking.
+    ///
+    /// The resource is passed as [`Mut`], so it's only marked as changed if it
+    /// is actually written to.
+    pub fn with_resource<R: Resource, O>(
+        &mut self,
+        f: impl FnOnce(Mut<R>, WorldUi<'_, '_, U>) -> O,
+    ) -> Option<O> {
+        if !self.world.contains_resource::<R>() {
+            warn_once!("Resource {} not found", type_name::<R>());
+            return None;
+        }
+        let ui = &mut *self.ui;
+        let out = self
+            .world
+            .resource_scope(|world, resource: Mut<R>| f(resource, WorldUi { world, ui }));
+        Some(out)
+    }
+
     /// Calls the given closure with a [`WorldUi`] for each [`Ui`] instance in the
     /// given iterable.
     pub fn for_each<'s, E: 's>(&'s mut self, mut f: impl FnMut(WorldUi<'_, '_, E>))
@@ -111,18 +208,653 @@ impl WorldUi<'_, '_, Ui> {
         self.ui
     }
 
-    /// Adds a [`Widget`] to this [`Ui`] instance and calls the given
-    /// [`Responder`] with the [`egui::Response`] from the widget.
+    /// Returns the egui [`Context`] of the inner [`Ui`] instance, e.g. for
+    /// requesting repaints. Not to be confused with [`WorldCtx`].
+    ///
+    /// [`WorldCtx`]: crate::ctx::WorldCtx
+    #[inline]
+    pub fn egui_ctx(&self) -> &Context {
+        self.ui.ctx()
+    }
+
+    /// [`egui::Context::animate_bool`] on the inner [`Ui`] instance's context.
+    pub fn animate_bool(&self, id: Id, value: bool) -> f32 {
+        self.ui.ctx().animate_bool(id, value)
+    }
+
+    /// [`egui::Context::animate_bool_with_time`] on the inner [`Ui`] instance's
+    /// context.
+    pub fn animate_bool_with_time(&self, id: Id, value: bool, animation_time: f32) -> f32 {
+        self.ui
+            .ctx()
+            .animate_bool_with_time(id, value, animation_time)
+    }
+
+    /// [`egui::Context::animate_value_with_time`] on the inner [`Ui`] instance's
+    /// context.
+    pub fn animate_value_with_time(&self, id: Id, target_value: f32, animation_time: f32) -> f32 {
+        self.ui
+            .ctx()
+            .animate_value_with_time(id, target_value, animation_time)
+    }
+
+    /// [`Ui::allocate_rect`] on the inner [`Ui`] instance.
+    pub fn allocate_rect(&mut self, rect: Rect, sense: Sense) -> Response {
+        self.ui.allocate_rect(rect, sense)
+    }
+
+    /// [`Ui::interact`] on the inner [`Ui`] instance.
+    pub fn interact(&mut self, rect: Rect, id: Id, sense: Sense) -> Response {
+        self.ui.interact(rect, id, sense)
+    }
+
+    /// Interacts with the background of the inner [`Ui`] instance, i.e. its
+    /// [`Ui::min_rect`] so far, e.g. to make a whole row clickable.
+    ///
+    /// The interaction is placed behind all widgets in the [`Ui`], so they still
+    /// receive their own clicks. Call this after adding the row's contents.
+    pub fn interact_bg(&mut self, sense: Sense) -> Response {
+        self.ui
+            .interact(self.ui.min_rect(), self.ui.unique_id(), sense)
+    }
+
+    /// Gives keyboard focus to the widget with the given [`Response`], e.g. the
+    /// search box of a command palette.
+    ///
+    /// Focus requests take effect immediately, so this should only be called
+    /// in the frame in which the widget should be focused, e.g. when the flag
+    /// that opens the palette switches to `true`. Calling it every frame
+    /// prevents the user from focusing anything else.
+    pub fn request_focus(&mut self, response: &Response) {
+        response.request_focus();
+    }
+
+    /// Removes keyboard focus from the widget with the given [`Response`], if
+    /// it has it.
+    pub fn surrender_focus(&mut self, response: &Response) {
+        response.surrender_focus();
+    }
+
+    /// Returns whether the widget with the given [`Response`] has keyboard
+    /// focus.
+    pub fn has_focus(&self, response: &Response) -> bool {
+        response.has_focus()
+    }
+
+    /// Returns the [`Painter`] of the inner [`Ui`] instance.
+    #[inline]
+    pub fn painter(&self) -> &Painter {
+        self.ui.painter()
+    }
+
+    /// [`Ui::available_size`] of the inner [`Ui`] instance.
+    #[inline]
+    pub fn available_size(&self) -> Vec2 {
+        self.ui.available_size()
+    }
+
+    /// [`Ui::available_width`] of the inner [`Ui`] instance.
+    #[inline]
+    pub fn available_width(&self) -> f32 {
+        self.ui.available_width()
+    }
+
+    /// [`Ui::available_height`] of the inner [`Ui`] instance.
+    #[inline]
+    pub fn available_height(&self) -> f32 {
+        self.ui.available_height()
+    }
+
+    /// [`Ui::ui_contains_pointer`] of the inner [`Ui`] instance, i.e. whether
+    /// the pointer is over the [`Ui::min_rect`] so far, and not covered by
+    /// another layer, e.g. a window.
+    #[inline]
+    pub fn contains_pointer(&self) -> bool {
+        self.ui.ui_contains_pointer()
+    }
+
+    /// Returns whether the inner [`Ui`] instance is hovered, e.g. to show a
+    /// toolbar only while hovering a card. Unlike [`Self::contains_pointer`],
+    /// this uses the final rect of the [`Ui`] in the previous pass, so it also
+    /// works before any contents are added.
+    #[inline]
+    pub fn is_hovered(&self) -> bool {
+        self.ui.response().hovered()
+    }
+
+    /// Sets the cursor to the given icon while the inner [`Ui`] instance is
+    /// hovered, e.g. for resize handles or drag areas. See
+    /// [`Self::is_hovered`].
+    pub fn set_cursor_icon_on_hover(&self, icon: CursorIcon) {
+        if self.is_hovered() {
+            self.ui.ctx().set_cursor_icon(icon);
+        }
+    }
+
+    /// [`Context::request_discard`] on the context of the inner [`Ui`]
+    /// instance, e.g. for a content-sized widget whose first layout is only a
+    /// guess. See [`WorldCtx::enable_multipass`].
+    ///
+    /// [`WorldCtx::enable_multipass`]: crate::ctx::WorldCtx::enable_multipass
+    pub fn request_discard(&self, reason: impl Into<Cow<'static, str>>) {
+        self.ui.ctx().request_discard(reason);
+    }
+
+    /// [`Ui::clip_rect`] of the inner [`Ui`] instance.
+    #[inline]
+    pub fn clip_rect(&self) -> Rect {
+        self.ui.clip_rect()
+    }
+
+    /// [`Ui::min_rect`] of the inner [`Ui`] instance.
+    #[inline]
+    pub fn min_rect(&self) -> Rect {
+        self.ui.min_rect()
+    }
+
+    /// [`Ui::max_rect`] of the inner [`Ui`] instance.
+    #[inline]
+    pub fn max_rect(&self) -> Rect {
+        self.ui.max_rect()
+    }
+
+    /// [`Ui::set_min_width`] on the inner [`Ui`] instance.
+    #[inline]
+    pub fn set_min_width(&mut self, width: f32) {
+        self.ui.set_min_width(width);
+    }
+
+    /// [`Ui::set_min_height`] on the inner [`Ui`] instance.
+    #[inline]
+    pub fn set_min_height(&mut self, height: f32) {
+        self.ui.set_min_height(height);
+    }
+
+    /// [`Ui::set_width`] on the inner [`Ui`] instance.
+    #[inline]
+    pub fn set_width(&mut self, width: f32) {
+        self.ui.set_width(width);
+    }
+
+    /// [`Ui::set_height`] on the inner [`Ui`] instance.
+    #[inline]
+    pub fn set_height(&mut self, height: f32) {
+        self.ui.set_height(height);
+    }
+
+    /// Adds a [`Widget`] to this [`Ui`] instance and returns its output.
     pub fn add<W: IntoWidget<M>, M>(&mut self, widget: W) -> <W::Widget as Widget>::Out {
         let widget = widget.into_widget();
         widget.draw(self.reborrow())
     }
 
+    /// Adds the given [`Widget`]s in order and returns their outputs, e.g. for
+    /// a number of widgets only known at runtime.
+    pub fn add_many<W: IntoWidget<M>, M>(
+        &mut self,
+        widgets: Vec<W>,
+    ) -> Vec<<W::Widget as Widget>::Out> {
+        let mut outs = Vec::with_capacity(widgets.len());
+        for widget in widgets {
+            outs.push(self.add(widget));
+        }
+        outs
+    }
+
+    /// Adds the given [`Widget`]s in order, calling `separator` between each
+    /// pair of them, e.g. for breadcrumbs or toolbar separators. Returns the
+    /// outputs of the widgets.
+    pub fn intersperse<W: IntoWidget<M>, M>(
+        &mut self,
+        widgets: impl IntoIterator<Item = W>,
+        mut separator: impl FnMut(WorldUi<'_, '_, Ui>),
+    ) -> Vec<<W::Widget as Widget>::Out> {
+        let mut outs = Vec::new();
+        for (i, widget) in widgets.into_iter().enumerate() {
+            if i > 0 {
+                separator(self.reborrow());
+            }
+            outs.push(self.add(widget));
+        }
+        outs
+    }
+
+    /// Adds a [`Widget`] with the given size, e.g. for rows of equally sized
+    /// buttons. See [`Widget::draw_sized`].
+    pub fn add_sized<W: IntoWidget<M>, M>(
+        &mut self,
+        size: Vec2,
+        widget: W,
+    ) -> <W::Widget as Widget>::Out {
+        widget.into_widget().draw_sized(self.reborrow(), size)
+    }
+
+    /// Adds a [`Widget`] that is only interactive if `enabled` is `true`, e.g.
+    /// to gray out a single button. See [`Enabled`].
+    pub fn add_enabled<W: IntoWidget<M>, M>(
+        &mut self,
+        enabled: bool,
+        widget: W,
+    ) -> <W::Widget as Widget>::Out {
+        let widget = widget.into_widget();
+        self.show(Enabled(enabled), |ui| widget.draw(ui)).inner
+    }
+
+    /// Adds a [`Widget`] that is only shown if `visible` is `true`, but takes up
+    /// space either way. See [`Visible`].
+    pub fn add_visible<W: IntoWidget<M>, M>(
+        &mut self,
+        visible: bool,
+        widget: W,
+    ) -> <W::Widget as Widget>::Out {
+        let widget = widget.into_widget();
+        self.add_visible_ui(visible, |ui| widget.draw(ui)).inner
+    }
+
+    /// Calls the given closure with a [`WorldUi`] whose contents are only shown
+    /// if `visible` is `true`, but take up space either way.
+    pub fn add_visible_ui<R>(
+        &mut self,
+        visible: bool,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        self.show(Visible(visible), add_contents)
+    }
+
+    /// Adds a [`Widget`] horizontally centered in the available width, using
+    /// [`Widget::measure`] to find its size. Widgets that can't be measured are
+    /// left-aligned instead.
+    pub fn add_centered<W: IntoWidget<M>, M>(&mut self, widget: W) -> <W::Widget as Widget>::Out {
+        let widget = widget.into_widget();
+        self.horizontal(|mut ui| {
+            if let Some(size) = widget.measure(ui.ui()) {
+                let space = (ui.available_width() - size.x) / 2.0;
+                ui.ui_mut().add_space(space.max(0.0));
+            }
+            widget.draw(ui)
+        })
+        .inner
+    }
+
+    /// Adds a [`Widget`] to this [`Ui`] instance and calls the given
+    /// [`Responder`] with the [`Response`] from the widget.
+    ///
+    /// Unlike [`Self::add`], this only accepts widgets that output a
+    /// [`Response`], which is why it is a separate method. See [`SetResource`]
+    /// for an example.
+    ///
+    /// [`SetResource`]: crate::responder::SetResource
+    pub fn add_with<W, WM, R, RM>(&mut self, widget: W, responder: R) -> Response
+    where
+        W: IntoWidget<WM>,
+        W::Widget: Widget<Out = Response>,
+        R: IntoResponder<RM>,
+    {
+        let response = self.add(widget);
+        responder.into_responder().respond(self.world, &response);
+        response
+    }
+
+    /// Adds an [`ImageButton`] showing the given Bevy [`Image`], registering
+    /// it with `bevy_egui` if needed. If `selected` is `true`, the button is
+    /// highlighted, e.g. for toggle-style toolbar buttons.
+    pub fn image_button(&mut self, image: &Handle<Image>, size: Vec2, selected: bool) -> Response {
+        match self.texture_id(image) {
+            Some(texture_id) => self
+                .ui
+                .add(ImageButton::new(egui::Image::new((texture_id, size))).selected(selected)),
+            None => self.ui.allocate_response(size, Sense::click()),
+        }
+    }
+
+    /// Adds a [`Spinner`] to this [`Ui`] instance.
+    pub fn spinner(&mut self) -> Response {
+        self.add(Spinner::new())
+    }
+
+    /// Adds a [`ProgressBar`] with the given fraction to this [`Ui`] instance.
+    pub fn progress_bar(&mut self, fraction: f32) -> Response {
+        self.add(ProgressBar::new(fraction))
+    }
+
+    /// Adds a [`Separator`] to this [`Ui`] instance.
+    pub fn separator(&mut self) -> Response {
+        self.add(Separator::default())
+    }
+
+    /// Adds a [`StyledButton`] with the given text and variant, colored
+    /// according to the [`ButtonTheme`] resource.
+    ///
+    /// [`ButtonTheme`]: crate::widget::ButtonTheme
+    pub fn styled_button(
+        &mut self,
+        text: impl Into<WidgetText>,
+        variant: ButtonVariant,
+    ) -> Response {
+        self.add(StyledButton::new(text, variant))
+    }
+
+    /// [`Ui::selectable_label`] on the inner [`Ui`] instance.
+    pub fn selectable_label(&mut self, checked: bool, text: impl Into<WidgetText>) -> Response {
+        self.ui.selectable_label(checked, text)
+    }
+
+    /// Adds a single [`Label`] made up of the given [`RichText`] segments, e.g.
+    /// log lines with a colored severity prefix.
+    pub fn rich_label(
+        &mut self,
+        segments: impl IntoIterator<Item = impl Into<RichText>>,
+    ) -> Response {
+        let style = self.ui.style();
+        let mut job = LayoutJob::default();
+        for segment in segments {
+            segment
+                .into()
+                .append_to(&mut job, style, FontSelection::Default, Align::Center);
+        }
+        self.ui.label(job)
+    }
+
+    /// Adds a vertical list of selectable labels for the given values, with
+    /// `current` set to the value of the clicked item, e.g. for menus.
+    ///
+    /// While an item is focused, the Up and Down arrow keys move the selection
+    /// and are consumed from egui's input. Enter and Space select the focused
+    /// item like a click. The returned [`Response`] covers the whole list and
+    /// is [`changed`](Response::changed) if the selection changed.
+    pub fn select_list<T: PartialEq + Clone>(
+        &mut self,
+        items: &[(T, String)],
+        current: &mut T,
+    ) -> Response {
+        let row = self.ui.vertical(|ui| {
+            let mut ids = Vec::with_capacity(items.len());
+            let mut focused = None;
+            let mut changed = false;
+            for (index, (value, label)) in items.iter().enumerate() {
+                let response = ui.selectable_label(*current == *value, label);
+                if response.clicked() && *current != *value {
+                    *current = value.clone();
+                    changed = true;
+                }
+                if response.has_focus() {
+                    focused = Some(index);
+                    // Keep egui from moving the focus on its own.
+                    let filter = EventFilter {
+                        vertical_arrows: true,
+                        ..Default::default()
+                    };
+                    ui.memory_mut(|memory| memory.set_focus_lock_filter(response.id, filter));
+                }
+                ids.push(response.id);
+            }
+            let Some(index) = focused else {
+                return changed;
+            };
+            let (up, down) = ui.input_mut(|input| {
+                (
+                    input.consume_key(Modifiers::NONE, Key::ArrowUp),
+                    input.consume_key(Modifiers::NONE, Key::ArrowDown),
+                )
+            });
+            let index = if up {
+                index.saturating_sub(1)
+            } else if down {
+                (index + 1).min(ids.len() - 1)
+            } else {
+                return changed;
+            };
+            let value = &items[index].0;
+            if *current != *value {
+                *current = value.clone();
+                changed = true;
+            }
+            ui.memory_mut(|memory| memory.request_focus(ids[index]));
+            changed
+        });
+        let mut response = row.response;
+        if row.inner {
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Adds a [`Label`] whose text can be selected and copied by the user,
+    /// e.g. for entity ids in inspectors.
+    pub fn selectable_label_text(&mut self, text: impl Into<String>) -> Response {
+        self.ui.add(Label::new(text.into()).selectable(true))
+    }
+
+    /// Adds a [`Label`] that is truncated with an ellipsis if it doesn't fit
+    /// the available width, e.g. for entity names in fixed-width columns. When
+    /// truncated, the full text is shown on hover.
+    pub fn truncated_label(&mut self, text: impl Into<String>) -> Response {
+        self.ui.add(Label::new(text.into()).truncate())
+    }
+
+    /// Adds a hyperlink with the given label that sends an [`OpenUrlRequest`]
+    /// event when clicked, instead of relying on egui's URL handling.
+    ///
+    /// The event needs to be registered with `App::add_event`. Handle it with
+    /// an `EventReader<OpenUrlRequest>`, e.g. by opening the URL in a browser.
+    pub fn hyperlink_to(&mut self, label: impl Into<WidgetText>, url: impl ToString) -> Response {
+        let url = url.to_string();
+        let response = self.ui.link(label).on_hover_text(&url);
+        if response.clicked() || response.middle_clicked() {
+            let new_tab = response.middle_clicked() || self.ui.input(|i| i.modifiers.any());
+            if self.world.contains_resource::<Events<OpenUrlRequest>>() {
+                self.world.send_event(OpenUrlRequest { url, new_tab });
+            } else {
+                warn_once!("Event {} not registered", type_name::<OpenUrlRequest>());
+            }
+        }
+        response
+    }
+
+    /// Adds a small button that copies the given text to the clipboard when
+    /// clicked, using [`egui::Context::copy_text`].
+    pub fn copy_button(&mut self, text: impl Into<String>) -> Response {
+        let response = self.ui.small_button("📋").on_hover_text("Copy");
+        if response.clicked() {
+            self.ui.ctx().copy_text(text.into());
+        }
+        response
+    }
+
+    /// [`Ui::selectable_value`] on the inner [`Ui`] instance.
+    pub fn selectable_value<T: PartialEq>(
+        &mut self,
+        current: &mut T,
+        selected: T,
+        text: impl Into<WidgetText>,
+    ) -> Response {
+        self.ui.selectable_value(current, selected, text)
+    }
+
+    /// Like [`Self::selectable_value`], but the current value is fetched from
+    /// the [`World`] with the given closure, e.g. from a resource or component.
+    pub fn selectable_value_with<T: PartialEq>(
+        &mut self,
+        current: impl FnOnce(&mut World) -> &mut T,
+        selected: T,
+        text: impl Into<WidgetText>,
+    ) -> Response {
+        self.ui
+            .selectable_value(current(self.world), selected, text)
+    }
+
+    /// [`Ui::checkbox`] bound to a `bool` in the [`Resource`] `R`. The resource
+    /// is only marked as changed if the checkbox was toggled.
+    pub fn checkbox_resource<R: Resource>(
+        &mut self,
+        text: impl Into<WidgetText>,
+        accessor: impl FnOnce(&mut R) -> &mut bool,
+    ) -> Response {
+        self.edit_resource(|resource: Option<&mut R>, ui| match resource {
+            Some(resource) => ui.checkbox(accessor(resource), text),
+            None => ui.add_enabled(false, Checkbox::new(&mut false, text)),
+        })
+    }
+
+    /// [`Ui::text_edit_singleline`] bound to a [`String`] in the [`Resource`]
+    /// `R`. The resource is only marked as changed if the text was edited.
+    pub fn text_edit_singleline_resource<R: Resource>(
+        &mut self,
+        accessor: impl FnOnce(&mut R) -> &mut String,
+    ) -> Response {
+        self.edit_resource(|resource: Option<&mut R>, ui| match resource {
+            Some(resource) => ui.text_edit_singleline(accessor(resource)),
+            None => ui.add_enabled(false, TextEdit::singleline(&mut String::new())),
+        })
+    }
+
+    /// [`Ui::text_edit_multiline`] bound to a [`String`] in the [`Resource`]
+    /// `R`. The resource is only marked as changed if the text was edited.
+    pub fn text_edit_multiline_resource<R: Resource>(
+        &mut self,
+        accessor: impl FnOnce(&mut R) -> &mut String,
+    ) -> Response {
+        self.edit_resource(|resource: Option<&mut R>, ui| match resource {
+            Some(resource) => ui.text_edit_multiline(accessor(resource)),
+            None => ui.add_enabled(false, TextEdit::multiline(&mut String::new())),
+        })
+    }
+
+    /// Adds a label followed by [`DragValue`]s for the components of the given
+    /// [`Vec3`], e.g. for a translation or scale. The returned [`Response`]
+    /// covers the whole row and is [`changed`](Response::changed) if any
+    /// component was edited.
+    pub fn vec3_edit(&mut self, label: impl Into<WidgetText>, value: &mut Vec3) -> Response {
+        let row = self.ui.horizontal(|ui| {
+            ui.label(label);
+            let x = ui.add(DragValue::new(&mut value.x).speed(0.1).prefix("x: "));
+            let y = ui.add(DragValue::new(&mut value.y).speed(0.1).prefix("y: "));
+            let z = ui.add(DragValue::new(&mut value.z).speed(0.1).prefix("z: "));
+            x | y | z
+        });
+        row.response | row.inner
+    }
+
+    /// Like [`Self::vec3_edit`], but edits the given [`Quat`] as Euler angles
+    /// in degrees, e.g. for a rotation. The rotation is only written back if an
+    /// angle was edited, so it doesn't drift from the round trip.
+    pub fn quat_edit(&mut self, label: impl Into<WidgetText>, value: &mut Quat) -> Response {
+        let (y, x, z) = value.to_euler(EulerRot::YXZ);
+        let mut angles = Vec3::new(x, y, z).map(f32::to_degrees);
+        let row = self.ui.horizontal(|ui| {
+            ui.label(label);
+            let x = ui.add(DragValue::new(&mut angles.x).suffix("°").prefix("x: "));
+            let y = ui.add(DragValue::new(&mut angles.y).suffix("°").prefix("y: "));
+            let z = ui.add(DragValue::new(&mut angles.z).suffix("°").prefix("z: "));
+            x | y | z
+        });
+        if row.inner.changed() {
+            let angles = angles.map(f32::to_radians);
+            *value = Quat::from_euler(EulerRot::YXZ, angles.y, angles.x, angles.z);
+        }
+        row.response | row.inner
+    }
+
+    /// [`DragValue`] bound to a number in the [`Resource`] `R`, with the given
+    /// speed and clamp range. The resource is only marked as changed if the
+    /// value was edited.
+    pub fn drag_value_resource<R: Resource, T: Numeric>(
+        &mut self,
+        accessor: impl FnOnce(&mut R) -> &mut T,
+        speed: f64,
+        range: RangeInclusive<T>,
+    ) -> Response {
+        self.edit_resource(|resource: Option<&mut R>, ui| match resource {
+            Some(resource) => ui.add(DragValue::new(accessor(resource)).speed(speed).range(range)),
+            None => ui.add_enabled(false, DragValue::new(&mut T::from_f64(0.0))),
+        })
+    }
+
+    /// [`Ui::toggle_value`] bound to a `bool` in the [`Resource`] `R`. The
+    /// resource is only marked as changed if the value was toggled.
+    pub fn toggle_value_resource<R: Resource>(
+        &mut self,
+        text: impl Into<WidgetText>,
+        accessor: impl FnOnce(&mut R) -> &mut bool,
+    ) -> Response {
+        self.edit_resource(|resource: Option<&mut R>, ui| match resource {
+            Some(resource) => ui.toggle_value(accessor(resource), text),
+            None => ui.add_enabled(false, SelectableLabel::new(false, text)),
+        })
+    }
+
+    /// Adds a [`Widget`] that can be dragged and returns its [`Response`]
+    /// along with the drag delta for this frame, e.g. to move a transform.
+    ///
+    /// The delta isn't rounded, so slow drags still accumulate correctly when
+    /// it's added to a value every frame.
+    pub fn drag_delta<W, M>(&mut self, widget: W) -> (Response, Vec2)
+    where
+        W: IntoWidget<M>,
+        W::Widget: Widget<Out = Response>,
+    {
+        let response = self.add(widget).interact(Sense::drag());
+        let delta = response.drag_delta();
+        (response, delta)
+    }
+
+    /// Adds a [`ComboBox`] for selecting one of the given variants, bound to a
+    /// value in the [`Resource`] `R`. The resource is only marked as changed
+    /// if a different variant was selected.
+    pub fn enum_combo<T: PartialEq + Clone, R: Resource>(
+        &mut self,
+        id_salt: impl Hash,
+        accessor: impl FnOnce(&mut R) -> &mut T,
+        variants: &[(T, &str)],
+    ) -> Response {
+        self.edit_resource(|resource: Option<&mut R>, ui| {
+            let combo = ComboBox::from_id_salt(id_salt);
+            let Some(resource) = resource else {
+                return ui
+                    .add_enabled_ui(false, |ui| combo.show_ui(ui, |_| {}).response)
+                    .inner;
+            };
+            let current = accessor(resource);
+            let selected_text = variants
+                .iter()
+                .find(|(value, _)| value == current)
+                .map_or("", |(_, text)| *text);
+            let mut response = combo.selected_text(selected_text).show_ui(ui, |ui| {
+                variants.iter().fold(false, |changed, (value, text)| {
+                    ui.selectable_value(current, value.clone(), *text).changed() || changed
+                })
+            });
+            if response.inner == Some(true) {
+                response.response.mark_changed();
+            }
+            response.response
+        })
+    }
+
+    /// Adds a form row with the given label on the left and the [`Widget`]
+    /// right-aligned on the right.
+    pub fn field<W: IntoWidget<M>, M>(
+        &mut self,
+        label: impl Into<WidgetText>,
+        widget: W,
+    ) -> <W::Widget as Widget>::Out {
+        self.horizontal(|mut ui| {
+            ui.ui_mut().label(label);
+            ui.show(Layout::right_to_left(Align::Center), |mut ui| {
+                ui.add(widget)
+            })
+            .inner
+        })
+        .inner
+    }
+
     /// Runs the given system with this [`Ui`] instance and returns the output.
+    ///
+    /// See [`UiSystemError`] for the ways in which this can fail.
     pub fn run_cached<I, O, M, S>(
         &mut self,
         system: S,
-    ) -> Result<<S::System as System>::Out, RegisteredSystemError<I, O>>
+    ) -> Result<<S::System as System>::Out, UiSystemError>
     where
         S: IntoSystem<I, O, M> + 'static,
         I: for<'a> SystemInput<Inner<'a>: From<&'a mut Ui>> + 'static,
@@ -130,15 +862,18 @@ impl WorldUi<'_, '_, Ui> {
     {
         self.world
             .run_system_cached_with(system, I::Inner::from(self.ui))
+            .map_err(UiSystemError::from)
     }
 
     /// Runs the given system with this [`Ui`] instance and the given extra data
     /// `E`, and returns the output.
+    ///
+    /// See [`UiSystemError`] for the ways in which this can fail.
     pub fn run_cached_with<'s: 'e, 'e, S, E, O, M>(
         &'s mut self,
         system: S,
         extra: E::Inner<'e>,
-    ) -> Result<<S::System as System>::Out, RegisteredSystemError<Draw<'static, E>, O>>
+    ) -> Result<<S::System as System>::Out, UiSystemError>
     where
         S: IntoSystem<Draw<'static, E>, O, M> + 'static,
         E: SystemInput + 'static,
@@ -146,6 +881,40 @@ impl WorldUi<'_, '_, Ui> {
     {
         self.world
             .run_system_cached_with(system, Draw::new(self.ui, extra))
+            .map_err(UiSystemError::from)
+    }
+
+    /// Like [`Self::run_cached`], but logs a warning and returns [`None`] if
+    /// the system fails to run.
+    pub fn run_cached_or_warn<I, O, M, S>(
+        &mut self,
+        system: S,
+    ) -> Option<<S::System as System>::Out>
+    where
+        S: IntoSystem<I, O, M> + 'static,
+        I: for<'a> SystemInput<Inner<'a>: From<&'a mut Ui>> + 'static,
+        O: 'static,
+    {
+        self.run_cached(system)
+            .map_err(|err| warn!("Failed to run UI system: {err}"))
+            .ok()
+    }
+
+    /// Like [`Self::run_cached_with`], but logs a warning and returns [`None`]
+    /// if the system fails to run.
+    pub fn run_cached_with_or_warn<'s: 'e, 'e, S, E, O, M>(
+        &'s mut self,
+        system: S,
+        extra: E::Inner<'e>,
+    ) -> Option<<S::System as System>::Out>
+    where
+        S: IntoSystem<Draw<'static, E>, O, M> + 'static,
+        E: SystemInput + 'static,
+        O: 'static,
+    {
+        self.run_cached_with(system, extra)
+            .map_err(|err| warn!("Failed to run UI system: {err}"))
+            .ok()
     }
 
     /// Shows a [`Container`] and calls the given closure with a [`WorldUi`] that
@@ -158,6 +927,48 @@ impl WorldUi<'_, '_, Ui> {
         container.show(self.reborrow(), add_contents)
     }
 
+    /// Shows a [`Canvas`] of the given size and calls the given closure with a
+    /// [`WorldUi`], a [`Painter`] clipped to the canvas, and the canvas' rect.
+    pub fn canvas<R>(
+        &mut self,
+        size: Vec2,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>, &Painter, Rect) -> R,
+    ) -> R {
+        self.show(Canvas { size }, |ui| {
+            let painter = ui.painter().clone();
+            let rect = ui.max_rect();
+            add_contents(ui, &painter, rect)
+        })
+    }
+
+    /// Shows [`Tabs`] and calls the given closure with a [`WorldUi`] and the
+    /// index of the selected tab.
+    pub fn tabs<R>(
+        &mut self,
+        id_salt: impl Hash,
+        tabs: &[&str],
+        selected: &mut usize,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>, usize) -> R,
+    ) -> R {
+        let mut tabs = Tabs {
+            id: Id::new(id_salt),
+            tabs,
+            selected,
+        };
+        tabs.show_bar(self.ui);
+        let index = *tabs.selected;
+        add_contents(self.reborrow(), index)
+    }
+
+    /// Calls the given closure with a [`WorldUi`] whose items are placed
+    /// without spacing between them. See [`Spacing`].
+    pub fn tight<R>(
+        &mut self,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        self.show(Spacing(Vec2::ZERO), add_contents)
+    }
+
     /// [`Ui::group`] with [`World`] access.
     pub fn group<R>(
         &mut self,
@@ -195,6 +1006,202 @@ impl WorldUi<'_, '_, Ui> {
         })
     }
 
+    /// Calls the given closure with a [`WorldUi`], then paints a background
+    /// behind its contents, e.g. a custom card shape. The shape returned by
+    /// `paint` is sized to the final rect of the contents, which is only known
+    /// after they have been added.
+    pub fn background<R, S: Into<Shape>>(
+        &mut self,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+        paint: impl FnOnce(&Painter, Rect) -> S,
+    ) -> InnerResponse<R> {
+        let index = self.ui.painter().add(Shape::Noop);
+        let response = self.scope(add_contents);
+        let painter = self.ui.painter();
+        painter.set(index, paint(painter, response.response.rect));
+        response
+    }
+
+    /// Calls the given closure with a [`WorldUi`] whose contents are clipped to
+    /// the given rect, e.g. for a ticker whose text scrolls through a smaller
+    /// window. The clip rect of this [`Ui`] instance is left untouched.
+    pub fn with_clip_rect<R>(
+        &mut self,
+        rect: Rect,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        self.scope(|mut ui| {
+            ui.ui_mut().set_clip_rect(rect);
+            add_contents(ui)
+        })
+    }
+
+    /// Calls the given closure with a [`WorldUi`] placed at the given rect,
+    /// e.g. for a badge pinned to the corner of a button. Unlike
+    /// [`Self::scope_builder`], the contents overlap other widgets and don't
+    /// affect the layout of this [`Ui`] instance.
+    ///
+    /// The contents are painted on the same layer, on top of widgets added
+    /// before.
+    pub fn overlay_at<R>(
+        &mut self,
+        rect: Rect,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        let (world, ui) = self.reborrow().into_parts();
+        let mut child = ui.new_child(UiBuilder::new().max_rect(rect));
+        let inner = add_contents(WorldUi::new(world, &mut child));
+        let response = ui.interact(child.min_rect(), child.unique_id(), Sense::hover());
+        InnerResponse::new(inner, response)
+    }
+
+    /// [`Ui::with_layer_id`] with [`World`] access, painting the contents on
+    /// the given layer, e.g. an [`Order::Tooltip`] layer for a drag preview
+    /// that should be drawn above everything else. The contents are still laid
+    /// out in this [`Ui`] instance.
+    ///
+    /// [`Order::Tooltip`]: egui::Order::Tooltip
+    pub fn with_layer<R>(
+        &mut self,
+        layer_id: LayerId,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        let (world, ui) = self.reborrow().into_parts();
+        ui.with_layer_id(layer_id, |ui| {
+            let ui = WorldUi::new(world, ui);
+            add_contents(ui)
+        })
+    }
+
+    /// [`Self::scope`] whose contents get their own id scope derived from the
+    /// given salt, e.g. for showing a reusable component multiple times in a
+    /// loop. Like [`Ui::push_id`] with [`World`] access.
+    pub fn scope_with_id<R>(
+        &mut self,
+        id_salt: impl Hash,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        self.scope_builder(UiBuilder::new().id_salt(id_salt), add_contents)
+    }
+
+    /// [`Ui::dnd_drag_source`] with [`World`] access.
+    pub fn dnd_drag_source<Payload: Any + Send + Sync, R>(
+        &mut self,
+        id: Id,
+        payload: Payload,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        let (world, ui) = self.reborrow().into_parts();
+        ui.dnd_drag_source(id, payload, |ui| {
+            let ui = WorldUi::new(world, ui);
+            add_contents(ui)
+        })
+    }
+
+    /// [`Ui::dnd_drop_zone`] with [`World`] access. The dropped payload, if any,
+    /// is returned after the closure, so it can be acted upon with full
+    /// [`World`] access.
+    pub fn dnd_drop_zone<Payload: Any + Send + Sync, R>(
+        &mut self,
+        frame: Frame,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> (InnerResponse<R>, Option<Arc<Payload>>) {
+        let (world, ui) = self.reborrow().into_parts();
+        ui.dnd_drop_zone(frame, |ui| {
+            let ui = WorldUi::new(world, ui);
+            add_contents(ui)
+        })
+    }
+
+    /// Shows [`TransferLists`] with the given items, rendering each item with
+    /// the given closure. Returns the list and index of an item dropped onto
+    /// the other list, along with that list.
+    pub fn dnd_transfer<T>(
+        &mut self,
+        id_salt: impl Hash,
+        left: &[T],
+        right: &[T],
+        add_item: impl FnMut(WorldUi, &T),
+    ) -> Option<(TransferList, usize, TransferList)> {
+        self.add(TransferLists::new(id_salt, left, right, add_item))
+    }
+
+    /// Returns an [`Id`] for the given [`Entity`] that is unique to this
+    /// [`Ui`] instance, so that the same entity can appear in multiple places
+    /// without id clashes.
+    pub fn entity_id(&self, entity: Entity) -> Id {
+        self.ui.id().with(entity)
+    }
+
+    /// [`Ui::push_id`] with the given [`Entity`] and [`World`] access, for
+    /// rendering per-entity UI fragments with stable ids.
+    pub fn with_entity<R>(
+        &mut self,
+        entity: Entity,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        let (world, ui) = self.reborrow().into_parts();
+        ui.push_id(entity, |ui| {
+            let ui = WorldUi::new(world, ui);
+            add_contents(ui)
+        })
+    }
+
+    /// Calls the given closure for each [`Entity`] matching the query data `D`
+    /// and filter `F`, e.g. to draw a row per entity. Each call gets its own
+    /// id scope like [`Self::with_entity`].
+    ///
+    /// The matching entities are collected with a cached query before any of
+    /// them are drawn, so the closure is free to mutate the [`World`]. For the
+    /// same reason, the closure gets the entity rather than the query item:
+    /// the item would borrow the [`World`] that the closure's [`WorldUi`] has
+    /// mutable access to. Use e.g. [`World::get`] through the [`WorldUi`] to
+    /// access the entity's components instead.
+    ///
+    /// Logs a warning and draws nothing if the query fails to run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_ecs::{component::Component, query::With};
+    /// use evergreen_egui::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Name(String);
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// fn enemy_list(mut ui: WorldUi) {
+    ///     ui.for_query::<&Name, With<Enemy>>(|mut ui, entity| {
+    ///         let name = ui.get::<Name>(entity).map(|name| name.0.clone());
+    ///         ui.ui_mut().label(name.unwrap_or_default());
+    ///         if ui.ui_mut().button("Despawn").clicked() {
+    ///             ui.despawn(entity);
+    ///         }
+    ///     });
+    /// }
+    /// ```
+    pub fn for_query<D: QueryData + 'static, F: QueryFilter + 'static>(
+        &mut self,
+        mut f: impl FnMut(WorldUi<'_, '_, Ui>, Entity),
+    ) {
+        fn matching<D: QueryData, F: QueryFilter>(query: Query<(Entity, D), F>) -> Vec<Entity> {
+            query.iter().map(|(entity, _)| entity).collect()
+        }
+
+        let Ok(entities) = self
+            .world
+            .run_system_cached(matching::<D, F>)
+            .map_err(|err| warn_once!("Failed to query entities: {err}"))
+        else {
+            return;
+        };
+        for entity in entities {
+            self.with_entity(entity, |ui| f(ui, entity));
+        }
+    }
+
     /// [`Ui::collapsing`] with [`World`] access.
     pub fn collapsing<R>(
         &mut self,
@@ -208,6 +1215,35 @@ impl WorldUi<'_, '_, Ui> {
         })
     }
 
+    /// Like [`Self::collapsing`], but the section starts open if
+    /// `default_open` is `true`. Only the first frame is affected; afterwards
+    /// the section remembers whether the user opened or closed it.
+    pub fn collapsing_default_open<R>(
+        &mut self,
+        heading: impl Into<WidgetText>,
+        default_open: bool,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> CollapsingResponse<R> {
+        let header = CollapsingHeader::new(heading).default_open(default_open);
+        self.show(header, add_contents)
+    }
+
+    /// Returns whether the collapsing section with the given id is open, e.g.
+    /// the id of the [`CollapsingResponse::header_response`] of a
+    /// [`CollapsingHeader`]. Sections that haven't been shown yet are closed.
+    pub fn collapsing_is_open(&self, id: Id) -> bool {
+        CollapsingState::load(self.ui.ctx(), id).is_some_and(|state| state.is_open())
+    }
+
+    /// Opens or closes the collapsing section with the given id, e.g. for
+    /// "Expand All" buttons. See [`Self::collapsing_is_open`].
+    pub fn set_collapsing_open(&mut self, id: Id, open: bool) {
+        let ctx = self.ui.ctx();
+        let mut state = CollapsingState::load_with_default_open(ctx, id, false);
+        state.set_open(open);
+        state.store(ctx);
+    }
+
     /// [`Ui::indent`] with [`World`] access.
     pub fn indent<R>(
         &mut self,
@@ -269,6 +1305,16 @@ impl WorldUi<'_, '_, Ui> {
         })
     }
 
+    /// Like [`Self::horizontal_wrapped`], but with the given [`Layout`], e.g.
+    /// to right-align or justify wrapped rows. Wrapping is always enabled.
+    pub fn horizontal_wrapped_with<R>(
+        &mut self,
+        layout: Layout,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<R> {
+        self.show(layout.with_main_wrap(true), add_contents)
+    }
+
     /// [`Ui::vertical`] with [`World`] access.
     pub fn vertical<R>(
         &mut self,
@@ -293,7 +1339,7 @@ impl WorldUi<'_, '_, Ui> {
         })
     }
 
-    /// [`Ui::vertical_top`] with [`World`] access.
+    /// [`Ui::vertical_centered_justified`] with [`World`] access.
     pub fn vertical_centered_justified<R>(
         &mut self,
         add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
@@ -317,7 +1363,7 @@ impl WorldUi<'_, '_, Ui> {
         })
     }
 
-    /// [`Ui::group`] with [`World`] access.
+    /// [`Ui::columns`] with [`World`] access.
     pub fn columns<R>(
         &mut self,
         columns: usize,
@@ -330,6 +1376,16 @@ impl WorldUi<'_, '_, Ui> {
         })
     }
 
+    /// Like [`Self::columns`], but with column widths proportional to the given
+    /// weights. See [`Columns`].
+    pub fn columns_weighted<R>(
+        &mut self,
+        weights: &[f32],
+        add_contents: impl FnOnce(WorldUi<'_, '_, [Ui]>) -> R,
+    ) -> R {
+        self.show(Columns(weights), add_contents)
+    }
+
     /// [`Ui::columns`] with a constant number of columns.
     pub fn columns_const<const NUM_COL: usize, R>(
         &mut self,
@@ -342,7 +1398,77 @@ impl WorldUi<'_, '_, Ui> {
         })
     }
 
+    /// Shows a [`ScrollArea`] in the given direction with [`World`] access.
+    pub fn scroll<R>(
+        &mut self,
+        dir: ScrollDir,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> ScrollAreaOutput<R> {
+        self.show(ScrollArea::from(dir), add_contents)
+    }
+
+    /// [`ScrollArea::horizontal`] with [`World`] access.
+    pub fn scroll_horizontal<R>(
+        &mut self,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> ScrollAreaOutput<R> {
+        self.scroll(ScrollDir::Horizontal, add_contents)
+    }
+
+    /// [`ScrollArea::vertical`] with [`World`] access.
+    pub fn scroll_vertical<R>(
+        &mut self,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> ScrollAreaOutput<R> {
+        self.scroll(ScrollDir::Vertical, add_contents)
+    }
+
+    /// [`ScrollArea::both`] with [`World`] access.
+    pub fn scroll_both<R>(
+        &mut self,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> ScrollAreaOutput<R> {
+        self.scroll(ScrollDir::Both, add_contents)
+    }
+
+    /// Marks the current position as a scroll target with the given id, e.g.
+    /// a section heading. If [`Self::scroll_to`] was called with the same id,
+    /// the surrounding [`ScrollArea`] is scrolled so this position is at the
+    /// top.
+    ///
+    /// The id is global, so a table of contents anywhere in the UI can scroll
+    /// to anchors in a different content area.
+    pub fn anchor(&mut self, id_salt: impl Hash) {
+        let id = Id::new(id_salt);
+        let requested = self.ui.ctx().data_mut(|data| {
+            let requested = data.get_temp::<Id>(scroll_target_id()) == Some(id);
+            if requested {
+                data.remove::<Id>(scroll_target_id());
+            }
+            requested
+        });
+        if requested {
+            let rect = Rect::from_min_size(self.ui.cursor().min, Vec2::ZERO);
+            self.ui.scroll_to_rect(rect, Some(Align::TOP));
+        }
+    }
+
+    /// Requests scrolling to the [`Self::anchor`] with the given id, e.g. when
+    /// clicking an entry in a table of contents. The request is handled when
+    /// the anchor is shown next, which may be in the following frame.
+    pub fn scroll_to(&mut self, id_salt: impl Hash) {
+        let id = Id::new(id_salt);
+        self.ui
+            .ctx()
+            .data_mut(|data| data.insert_temp(scroll_target_id(), id));
+        self.ui.ctx().request_repaint();
+    }
+
     /// [`Ui::menu_button`] with [`World`] access.
+    ///
+    /// If called from within a menu, this creates a button for a sub-menu
+    /// instead, so menus can be nested to any depth. Closing a menu closes all
+    /// of its sub-menus.
     pub fn menu_button<R>(
         &mut self,
         title: impl Into<WidgetText>,
@@ -354,6 +1480,67 @@ impl WorldUi<'_, '_, Ui> {
             add_contents(ui)
         })
     }
+
+    /// Shows a menu with the given title and [`World`] access, e.g. in a
+    /// [`egui::menu::bar`]. Same as [`Self::menu_button`].
+    ///
+    /// Calling this from within another menu shows a sub-menu, like
+    /// [`Ui::menu_button`] does, so menus such as Settings → Graphics →
+    /// Resolution can be nested to any depth. Closing a menu, e.g. with
+    /// [`Self::close_menu`] or by clicking outside of it, closes all of its
+    /// sub-menus.
+    pub fn menu<R>(
+        &mut self,
+        title: impl Into<WidgetText>,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<Option<R>> {
+        self.menu_button(title, add_contents)
+    }
+
+    /// [`Ui::menu_image_button`] with [`World`] access, showing the given Bevy
+    /// [`Image`] and registering it with `bevy_egui` if needed.
+    ///
+    /// If the image isn't available, an empty region of the given size is
+    /// allocated instead and no menu is shown.
+    pub fn menu_image_button<R>(
+        &mut self,
+        image: &Handle<Image>,
+        size: Vec2,
+        add_contents: impl FnOnce(WorldUi<'_, '_, Ui>) -> R,
+    ) -> InnerResponse<Option<R>> {
+        let Some(texture_id) = self.texture_id(image) else {
+            let response = self.ui.allocate_response(size, Sense::click());
+            return InnerResponse::new(None, response);
+        };
+        let (world, ui) = self.reborrow().into_parts();
+        ui.menu_image_button(egui::Image::new((texture_id, size)), |ui| {
+            let ui = WorldUi::new(world, ui);
+            add_contents(ui)
+        })
+    }
+
+    /// [`Ui::close_menu`] on the inner [`Ui`] instance, closing the menu
+    /// hierarchy this is called in.
+    pub fn close_menu(&mut self) {
+        self.ui.close_menu();
+    }
+
+    /// Calls the given closure with the [`Resource`] `R`, if it exists, and
+    /// only marks it as changed if the returned [`Response`] reports a change.
+    fn edit_resource<R: Resource>(
+        &mut self,
+        f: impl FnOnce(Option<&mut R>, &mut Ui) -> Response,
+    ) -> Response {
+        let Some(mut resource) = self.world.get_resource_mut::<R>() else {
+            warn_once!("Resource {} not found", type_name::<R>());
+            return f(None, self.ui);
+        };
+        let response = f(Some(resource.bypass_change_detection()), self.ui);
+        if response.changed() {
+            resource.set_changed();
+        }
+        response
+    }
 }
 
 impl WorldUi<'_, '_, [Ui]> {
@@ -382,6 +1569,103 @@ impl WorldUi<'_, '_, [Ui]> {
     }
 }
 
+#[cfg(feature = "egui_plot")]
+impl WorldUi<'_, '_, egui_plot::PlotUi> {
+    /// Returns a reference to the [`PlotUi`](egui_plot::PlotUi) instance.
+    #[inline]
+    pub fn plot_ui(&self) -> &egui_plot::PlotUi {
+        self.ui
+    }
+
+    /// Returns a mutable reference to the [`PlotUi`](egui_plot::PlotUi)
+    /// instance, e.g. for adding lines or points.
+    #[inline]
+    pub fn plot_ui_mut(&mut self) -> &mut egui_plot::PlotUi {
+        self.ui
+    }
+}
+
+/// A secondary [`World`] used to render UI against data that shouldn't affect
+/// the main world, e.g. previews of prefabs. See
+/// [`WorldUi::with_scratch_world`].
+#[derive(Resource, Default)]
+pub struct ScratchWorld(pub World);
+
+/// Event sent when a link added with [`WorldUi::hyperlink_to`] is clicked.
+#[derive(Event, Clone, Debug, PartialEq, Eq)]
+pub struct OpenUrlRequest {
+    /// The URL to open.
+    pub url: String,
+    /// Whether the URL should be opened in a new tab, e.g. because it was
+    /// middle-clicked.
+    pub new_tab: bool,
+}
+
+/// Error returned when a UI system fails to run, e.g. by
+/// [`WorldUi::run_cached`]. Each variant corresponds to a variant of
+/// [`RegisteredSystemError`], with the entity of the system's [`SystemId`]
+/// instead of the id itself, so the error doesn't depend on the system's input
+/// and output types.
+///
+/// Panics inside the system are not caught and propagate to the caller.
+///
+/// [`SystemId`]: bevy_ecs::system::SystemId
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UiSystemError {
+    /// The system with the given entity wasn't registered, e.g. because it was
+    /// removed from the world.
+    NotRegistered(Entity),
+    /// The cached system wasn't found, e.g. because it was removed from the
+    /// world.
+    NotCached,
+    /// The system with the given entity tried to run itself recursively.
+    Recursive(Entity),
+    /// The system with the given entity tried to remove itself.
+    SelfRemove(Entity),
+    /// The parameters of the system with the given entity failed validation,
+    /// e.g. because a resource it requires doesn't exist in the world. Unless
+    /// the system's parameter warn policy is changed, e.g. with
+    /// `param_warn_once`, Bevy panics in this case instead.
+    InvalidParams(Entity),
+}
+
+impl<I: SystemInput, O> From<RegisteredSystemError<I, O>> for UiSystemError {
+    fn from(err: RegisteredSystemError<I, O>) -> Self {
+        match err {
+            RegisteredSystemError::SystemIdNotRegistered(id) => {
+                UiSystemError::NotRegistered(id.entity())
+            }
+            RegisteredSystemError::SystemNotCached => UiSystemError::NotCached,
+            RegisteredSystemError::Recursive(id) => UiSystemError::Recursive(id.entity()),
+            RegisteredSystemError::SelfRemove(id) => UiSystemError::SelfRemove(id.entity()),
+            RegisteredSystemError::InvalidParams(id) => UiSystemError::InvalidParams(id.entity()),
+        }
+    }
+}
+
+impl fmt::Display for UiSystemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UiSystemError::NotRegistered(entity) => {
+                write!(f, "the system {entity} was not registered")
+            }
+            UiSystemError::NotCached => write!(f, "the cached system was not found"),
+            UiSystemError::Recursive(entity) => {
+                write!(f, "the system {entity} tried to run itself recursively")
+            }
+            UiSystemError::SelfRemove(entity) => {
+                write!(f, "the system {entity} tried to remove itself")
+            }
+            UiSystemError::InvalidParams(entity) => write!(
+                f,
+                "the data required by the system {entity} was not found in the world"
+            ),
+        }
+    }
+}
+
+impl Error for UiSystemError {}
+
 impl<U: ?Sized> Deref for WorldUi<'_, '_, U> {
     type Target = World;
 
@@ -397,3 +1681,9 @@ impl<U: ?Sized> DerefMut for WorldUi<'_, '_, U> {
         self.world
     }
 }
+
+/// The [`Id`] under which [`WorldUi::scroll_to`] stores the pending request in
+/// the [`Context`]'s temporary data.
+fn scroll_target_id() -> Id {
+    Id::new("evergreen_egui::scroll_target")
+}
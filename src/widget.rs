@@ -1,9 +1,34 @@
 //! Widgets for use in egui UIs.
 
-use std::ops::{Deref, DerefMut};
+use std::{
+    any::{type_name, TypeId},
+    hash::Hash,
+    ops::{Deref, DerefMut},
+};
 
-use bevy_ecs::system::SystemInput;
-use egui::{Response, Ui};
+use bevy_asset::Handle;
+use bevy_diagnostic::{DiagnosticPath, DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    entity::Entity,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    system::{Resource, SystemInput},
+    world::World,
+};
+use bevy_hierarchy::Children;
+use bevy_image::Image;
+use bevy_log::warn_once;
+use bevy_reflect::{
+    DynamicEnum, DynamicVariant, Enum, PartialReflect, ReflectMut, ReflectRef, TypeInfo,
+    VariantInfo,
+};
+use egui::{
+    collapsing_header::{paint_default_icon, CollapsingState},
+    pos2, vec2, Align, Button, CollapsingHeader, Color32, ComboBox, Context, DragValue,
+    FontSelection, Frame, Id, InnerResponse, Label, Layout, Rect, Response, ScrollArea,
+    SelectableLabel, Sense, Stroke, TextStyle, Ui, Vec2, WidgetText,
+};
 use variadics_please::all_tuples;
 
 use crate::ui::WorldUi;
@@ -15,6 +40,29 @@ pub trait Widget {
 
     /// Draws this widget to the given [`Ui`].
     fn draw(self, ui: WorldUi) -> Self::Out;
+
+    /// Draws this widget to the given [`Ui`] with the given size. By default,
+    /// the widget is drawn into a child [`Ui`] allocated with that size.
+    fn draw_sized(self, ui: WorldUi, size: Vec2) -> Self::Out
+    where
+        Self: Sized,
+    {
+        let (world, ui) = ui.into_parts();
+        ui.allocate_ui(size, |ui| self.draw(WorldUi::new(world, ui)))
+            .inner
+    }
+
+    /// Returns the size this widget would take up if drawn to the given
+    /// [`Ui`], or [`None`] if it can't be known without drawing it.
+    ///
+    /// Widgets from egui, added through their [`egui::Widget`] impl, always
+    /// return [`None`], as egui only sizes them while drawing them. Use
+    /// [`TextLabel`] or [`TextButton`] for text and buttons that can be
+    /// measured.
+    fn measure(&self, ui: &Ui) -> Option<Vec2> {
+        let _ = ui;
+        None
+    }
 }
 
 macro_rules! impl_widget_tuple {
@@ -29,10 +77,30 @@ macro_rules! impl_widget_tuple {
                 let ($($name,)*) = self;
                 ($($name.draw(ui.reborrow()),)*)
             }
+
+            #[allow(non_snake_case)]
+            fn measure(&self, ui: &Ui) -> Option<Vec2> {
+                let ($($name,)*) = self;
+                Some(stack_sizes(ui, &[$($name.measure(ui)?,)*]))
+            }
         }
     };
 }
 
+/// Returns the total size of widgets with the given sizes when laid out one
+/// after another in the given [`Ui`].
+fn stack_sizes(ui: &Ui, sizes: &[Vec2]) -> Vec2 {
+    let spacing = ui.spacing().item_spacing;
+    let gaps = sizes.len().saturating_sub(1) as f32;
+    let sum = sizes.iter().fold(Vec2::ZERO, |sum, size| sum + *size);
+    let max = sizes.iter().copied().fold(Vec2::ZERO, Vec2::max);
+    if ui.layout().is_horizontal() {
+        vec2(sum.x + spacing.x * gaps, max.y)
+    } else {
+        vec2(max.x, sum.y + spacing.y * gaps)
+    }
+}
+
 all_tuples!(
     #[doc(fake_variadic)]
     impl_widget_tuple,
@@ -53,6 +121,13 @@ where
     fn draw(self, mut ui: WorldUi) -> Self::Out {
         ui.ui_mut().add(self.0)
     }
+
+    fn draw_sized(self, mut ui: WorldUi, size: Vec2) -> Self::Out {
+        ui.ui_mut().add_sized(size, self.0)
+    }
+
+    // `measure` returns `None`, as `egui::Widget` has no way to query the size
+    // of a widget without drawing it.
 }
 
 /// [`SystemInput`] for drawing UI elements. Extra data can be passed as well.
@@ -176,3 +251,848 @@ where
         EguiWidget(self)
     }
 }
+
+/// [`Widget`] that renders a [`Label`] with the given text. Anything that
+/// converts into [`WidgetText`], e.g. `&str` or [`String`], can be added as
+/// this widget directly.
+pub struct TextLabel(pub WidgetText);
+
+impl Widget for TextLabel {
+    type Out = Response;
+
+    fn draw(self, mut ui: WorldUi) -> Self::Out {
+        ui.ui_mut().add(Label::new(self.0))
+    }
+
+    fn draw_sized(self, mut ui: WorldUi, size: Vec2) -> Self::Out {
+        ui.ui_mut().add_sized(size, Label::new(self.0))
+    }
+
+    fn measure(&self, ui: &Ui) -> Option<Vec2> {
+        let galley =
+            self.0
+                .clone()
+                .into_galley(ui, None, ui.available_width(), FontSelection::Default);
+        Some(galley.size())
+    }
+}
+
+#[doc(hidden)]
+pub struct TextLabelMarker;
+
+impl<T: Into<WidgetText>> IntoWidget<TextLabelMarker> for T {
+    type Widget = TextLabel;
+
+    fn into_widget(self) -> Self::Widget {
+        TextLabel(self.into())
+    }
+}
+
+/// [`Widget`] that renders a [`Button`] with the given text. Unlike adding a
+/// [`Button`] directly, this can be measured with [`Widget::measure`], e.g. to
+/// center it with [`WorldUi::add_centered`].
+pub struct TextButton(pub WidgetText);
+
+impl Widget for TextButton {
+    type Out = Response;
+
+    fn draw(self, mut ui: WorldUi) -> Self::Out {
+        ui.ui_mut().add(Button::new(self.0))
+    }
+
+    fn draw_sized(self, mut ui: WorldUi, size: Vec2) -> Self::Out {
+        ui.ui_mut().add_sized(size, Button::new(self.0))
+    }
+
+    fn measure(&self, ui: &Ui) -> Option<Vec2> {
+        // Mirrors how `Button` sizes itself when it only has text.
+        let padding = if ui.visuals().button_frame {
+            ui.spacing().button_padding
+        } else {
+            Vec2::ZERO
+        };
+        let wrap_width = ui.available_width() - 2.0 * padding.x;
+        let galley = self
+            .0
+            .clone()
+            .into_galley(ui, None, wrap_width, TextStyle::Button);
+        let mut size = galley.size() + 2.0 * padding;
+        size.y = size.y.max(ui.spacing().interact_size.y);
+        Some(size)
+    }
+}
+
+/// [`Widget`] that renders a Bevy [`Image`] with a tint and an optional UV
+/// rect, e.g. for colored icons from a shared atlas. The image is registered
+/// with `bevy_egui` automatically.
+pub struct TintedImage {
+    /// The image to render.
+    pub handle: Handle<Image>,
+    /// The size to render the image at.
+    pub size: Vec2,
+    /// The color to multiply the image with.
+    pub tint: Color32,
+    /// The normalized region of the image to render.
+    pub uv: Rect,
+}
+
+impl TintedImage {
+    /// Creates a new instance rendering the whole image without a tint.
+    pub fn new(handle: Handle<Image>, size: Vec2) -> Self {
+        TintedImage {
+            handle,
+            size,
+            tint: Color32::WHITE,
+            uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+        }
+    }
+
+    /// Sets the color to multiply the image with.
+    pub fn tint(mut self, tint: impl Into<Color32>) -> Self {
+        self.tint = tint.into();
+        self
+    }
+
+    /// Sets the normalized region of the image to render.
+    pub fn uv(mut self, uv: Rect) -> Self {
+        self.uv = uv;
+        self
+    }
+}
+
+impl Widget for TintedImage {
+    type Out = Response;
+
+    fn draw(self, mut ui: WorldUi) -> Self::Out {
+        let Some(texture_id) = ui.texture_id(&self.handle) else {
+            return ui.ui_mut().allocate_response(self.size, Sense::click());
+        };
+        let image = egui::Image::new((texture_id, self.size))
+            .tint(self.tint)
+            .uv(self.uv)
+            .sense(Sense::click());
+        ui.ui_mut().add(image)
+    }
+
+    fn measure(&self, _ui: &Ui) -> Option<Vec2> {
+        Some(self.size)
+    }
+}
+
+/// [`Widget`] that renders a list of items with drag handles, allowing the
+/// user to reorder them via drag and drop.
+///
+/// The list itself isn't modified. Instead, drawing it returns the indices
+/// `(from, to)` of a dropped item, so the move can be applied afterwards.
+pub struct ReorderableList<'a, T, F> {
+    id_salt: Id,
+    items: &'a [T],
+    add_item: F,
+}
+
+impl<'a, T, F> ReorderableList<'a, T, F>
+where
+    F: FnMut(WorldUi, &T),
+{
+    /// Creates a new list rendering each item with the given closure.
+    pub fn new(id_salt: impl Hash, items: &'a [T], add_item: F) -> Self {
+        ReorderableList {
+            id_salt: Id::new(id_salt),
+            items,
+            add_item,
+        }
+    }
+}
+
+/// Drag and drop payload of a [`ReorderableList`].
+struct ReorderPayload {
+    list: Id,
+    index: usize,
+}
+
+impl<T, F> Widget for ReorderableList<'_, T, F>
+where
+    F: FnMut(WorldUi, &T),
+{
+    type Out = Option<(usize, usize)>;
+
+    fn draw(mut self, mut ui: WorldUi) -> Self::Out {
+        let list = ui.ui().id().with(self.id_salt);
+        let mut moved = None;
+        for (index, item) in self.items.iter().enumerate() {
+            let row = ui
+                .horizontal(|mut ui| {
+                    let payload = ReorderPayload { list, index };
+                    ui.ui_mut()
+                        .dnd_drag_source(list.with(index), payload, |ui| ui.label("☰"));
+                    (self.add_item)(ui, item);
+                })
+                .response;
+
+            let is_own = |payload: &ReorderPayload| payload.list == list;
+            if row
+                .dnd_hover_payload::<ReorderPayload>()
+                .is_some_and(|payload| is_own(&payload))
+            {
+                let stroke = ui.ui().visuals().selection.stroke;
+                ui.ui()
+                    .painter()
+                    .hline(row.rect.x_range(), row.rect.top(), stroke);
+            }
+            if let Some(payload) = row.dnd_release_payload::<ReorderPayload>() {
+                if is_own(&payload) && payload.index != index {
+                    moved = Some((payload.index, index));
+                }
+            }
+        }
+        moved
+    }
+}
+
+/// One of the two lists of a [`TransferLists`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransferList {
+    /// The list in the left column.
+    Left,
+    /// The list in the right column.
+    Right,
+}
+
+/// [`Widget`] that renders two lists side by side, allowing the user to drag
+/// items from one list to the other, e.g. between "bench" and "active" units.
+///
+/// The lists themselves aren't modified. Instead, drawing them returns the
+/// list and index of a dropped item along with the list it was dropped onto,
+/// so the move can be applied afterwards.
+pub struct TransferLists<'a, T, F> {
+    id_salt: Id,
+    left: &'a [T],
+    right: &'a [T],
+    add_item: F,
+}
+
+impl<'a, T, F> TransferLists<'a, T, F>
+where
+    F: FnMut(WorldUi, &T),
+{
+    /// Creates new lists rendering each item with the given closure.
+    pub fn new(id_salt: impl Hash, left: &'a [T], right: &'a [T], add_item: F) -> Self {
+        TransferLists {
+            id_salt: Id::new(id_salt),
+            left,
+            right,
+            add_item,
+        }
+    }
+}
+
+/// Drag and drop payload of a [`TransferLists`].
+struct TransferPayload {
+    lists: Id,
+    from: TransferList,
+    index: usize,
+}
+
+impl<T, F> Widget for TransferLists<'_, T, F>
+where
+    F: FnMut(WorldUi, &T),
+{
+    type Out = Option<(TransferList, usize, TransferList)>;
+
+    fn draw(mut self, mut ui: WorldUi) -> Self::Out {
+        let lists = ui.ui().id().with(self.id_salt);
+        let frame = Frame::group(ui.ui().style());
+        let sides = [
+            (TransferList::Left, self.left),
+            (TransferList::Right, self.right),
+        ];
+        let mut moved = None;
+        ui.columns(2, |mut columns| {
+            for (column, (side, items)) in sides.into_iter().enumerate() {
+                let mut column = columns.at(column);
+                let (_, payload) = column.dnd_drop_zone::<TransferPayload, _>(frame, |mut ui| {
+                    let min_size =
+                        vec2(ui.ui().available_width(), ui.ui().spacing().interact_size.y);
+                    ui.ui_mut().set_min_size(min_size);
+                    for (index, item) in items.iter().enumerate() {
+                        let payload = TransferPayload {
+                            lists,
+                            from: side,
+                            index,
+                        };
+                        let id = lists.with((side, index));
+                        ui.dnd_drag_source(id, payload, |ui| (self.add_item)(ui, item));
+                    }
+                });
+                if let Some(payload) = payload {
+                    if payload.lists == lists && payload.from != side {
+                        moved = Some((payload.from, payload.index, side));
+                    }
+                }
+            }
+        });
+        moved
+    }
+}
+
+/// [`Widget`] that shows the smoothed values of Bevy diagnostics from the
+/// [`DiagnosticsStore`], e.g. FPS and frame time.
+///
+/// The diagnostics need to be registered, e.g. with
+/// [`FrameTimeDiagnosticsPlugin`] for the default paths.
+///
+/// [`FrameTimeDiagnosticsPlugin`]: bevy_diagnostic::FrameTimeDiagnosticsPlugin
+pub struct DiagnosticsOverlay {
+    /// The paths of the diagnostics to show.
+    pub paths: Vec<DiagnosticPath>,
+}
+
+impl DiagnosticsOverlay {
+    /// Creates a new instance showing the diagnostics with the given paths.
+    pub fn new(paths: impl IntoIterator<Item = DiagnosticPath>) -> Self {
+        DiagnosticsOverlay {
+            paths: paths.into_iter().collect(),
+        }
+    }
+
+    /// Adds a diagnostic to show.
+    pub fn with_path(mut self, path: DiagnosticPath) -> Self {
+        self.paths.push(path);
+        self
+    }
+}
+
+impl Default for DiagnosticsOverlay {
+    /// Shows the FPS and frame time.
+    fn default() -> Self {
+        DiagnosticsOverlay::new([
+            FrameTimeDiagnosticsPlugin::FPS,
+            FrameTimeDiagnosticsPlugin::FRAME_TIME,
+        ])
+    }
+}
+
+impl Widget for DiagnosticsOverlay {
+    type Out = Response;
+
+    fn draw(self, ui: WorldUi) -> Self::Out {
+        let (world, ui) = ui.into_parts();
+        let Some(store) = world.get_resource::<DiagnosticsStore>() else {
+            warn_once!("Resource {} not found", type_name::<DiagnosticsStore>());
+            return ui.label("No diagnostics");
+        };
+        ui.vertical(|ui| {
+            for path in &self.paths {
+                let value = store
+                    .get(path)
+                    .and_then(|diagnostic| Some((diagnostic.smoothed()?, &diagnostic.suffix)));
+                match value {
+                    Some((value, suffix)) => {
+                        ui.label(format!("{}: {value:.2}{suffix}", path.as_str()))
+                    }
+                    None => ui.label(format!("{}: -", path.as_str())),
+                };
+            }
+        })
+        .response
+    }
+}
+
+/// Variant of a [`StyledButton`], whose colors are taken from the
+/// [`ButtonTheme`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ButtonVariant {
+    /// The main action of a view, e.g. "Save".
+    #[default]
+    Primary,
+    /// A less prominent action, e.g. "Cancel".
+    Secondary,
+    /// A destructive action, e.g. "Delete".
+    Danger,
+}
+
+/// Colors of a single [`ButtonVariant`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ButtonColors {
+    /// The background color of the button.
+    pub fill: Color32,
+    /// The color of the button's text.
+    pub text: Color32,
+    /// The outline of the button.
+    pub stroke: Stroke,
+}
+
+impl ButtonColors {
+    /// Creates new colors with the given fill and text color, without an
+    /// outline.
+    pub fn new(fill: Color32, text: Color32) -> Self {
+        ButtonColors {
+            fill,
+            text,
+            stroke: Stroke::NONE,
+        }
+    }
+}
+
+/// Resource with the colors of each [`ButtonVariant`], read by
+/// [`StyledButton`]. If it doesn't exist, the default colors are used.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct ButtonTheme {
+    /// The colors of [`ButtonVariant::Primary`] buttons.
+    pub primary: ButtonColors,
+    /// The colors of [`ButtonVariant::Secondary`] buttons.
+    pub secondary: ButtonColors,
+    /// The colors of [`ButtonVariant::Danger`] buttons.
+    pub danger: ButtonColors,
+}
+
+impl ButtonTheme {
+    /// Returns the colors of the given variant.
+    pub fn colors(&self, variant: ButtonVariant) -> ButtonColors {
+        match variant {
+            ButtonVariant::Primary => self.primary,
+            ButtonVariant::Secondary => self.secondary,
+            ButtonVariant::Danger => self.danger,
+        }
+    }
+}
+
+impl Default for ButtonTheme {
+    fn default() -> Self {
+        ButtonTheme {
+            primary: ButtonColors::new(Color32::from_rgb(0, 109, 143), Color32::WHITE),
+            secondary: ButtonColors {
+                fill: Color32::TRANSPARENT,
+                text: Color32::GRAY,
+                stroke: Stroke::new(1.0, Color32::GRAY),
+            },
+            danger: ButtonColors::new(Color32::from_rgb(180, 40, 40), Color32::WHITE),
+        }
+    }
+}
+
+/// [`Widget`] that renders a [`Button`] colored according to its
+/// [`ButtonVariant`] and the [`ButtonTheme`] resource, so buttons look the
+/// same across an app.
+pub struct StyledButton {
+    /// The text of the button.
+    pub text: WidgetText,
+    /// The variant that determines the button's colors.
+    pub variant: ButtonVariant,
+}
+
+impl StyledButton {
+    /// Creates a new button with the given text and variant.
+    pub fn new(text: impl Into<WidgetText>, variant: ButtonVariant) -> Self {
+        StyledButton {
+            text: text.into(),
+            variant,
+        }
+    }
+}
+
+impl Widget for StyledButton {
+    type Out = Response;
+
+    fn draw(self, ui: WorldUi) -> Self::Out {
+        let (world, ui) = ui.into_parts();
+        let colors = match world.get_resource::<ButtonTheme>() {
+            Some(theme) => theme.colors(self.variant),
+            None => ButtonTheme::default().colors(self.variant),
+        };
+        let text = self.text.color(colors.text);
+        ui.add(Button::new(text).fill(colors.fill).stroke(colors.stroke))
+    }
+}
+
+/// [`Widget`] that renders an entity hierarchy, e.g. for scene outlines. Each
+/// node is drawn with the given closure, which receives a [`WorldUi`] for the
+/// node's row.
+///
+/// The tree is shown in its own vertical [`ScrollArea`], and only rows inside
+/// its visible region are built. Children of collapsed nodes aren't visited at
+/// all, so large hierarchies stay cheap to draw. Don't put the tree inside
+/// another vertical [`ScrollArea`], as that nests the two scroll areas.
+pub struct TreeView<'a, F> {
+    id_salt: Id,
+    roots: Vec<Entity>,
+    selected: Option<Entity>,
+    add_node: F,
+    on_click: Option<NodeCallback<'a>>,
+    on_expand: Option<ToggleCallback<'a>>,
+}
+
+/// Callback of [`TreeView::on_click`].
+type NodeCallback<'a> = Box<dyn FnMut(&mut World, Entity) + 'a>;
+
+/// Callback of [`TreeView::on_expand`].
+type ToggleCallback<'a> = Box<dyn FnMut(&mut World, Entity, bool) + 'a>;
+
+impl<'a, F> TreeView<'a, F>
+where
+    F: FnMut(WorldUi, Entity),
+{
+    /// Creates a new tree view with the given root entities, rendering each
+    /// node with the given closure.
+    pub fn new(id_salt: impl Hash, roots: impl IntoIterator<Item = Entity>, add_node: F) -> Self {
+        TreeView {
+            id_salt: Id::new(id_salt),
+            roots: roots.into_iter().collect(),
+            selected: None,
+            add_node,
+            on_click: None,
+            on_expand: None,
+        }
+    }
+
+    /// Highlights the row of the given entity.
+    pub fn selected(mut self, selected: Option<Entity>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Calls the given closure when a row is clicked.
+    pub fn on_click(mut self, f: impl FnMut(&mut World, Entity) + 'a) -> Self {
+        self.on_click = Some(Box::new(f));
+        self
+    }
+
+    /// Calls the given closure when a node is expanded or collapsed, with
+    /// `true` if it was expanded.
+    pub fn on_expand(mut self, f: impl FnMut(&mut World, Entity, bool) + 'a) -> Self {
+        self.on_expand = Some(Box::new(f));
+        self
+    }
+}
+
+/// Output of a [`TreeView`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TreeViewResponse {
+    /// The entity whose row was clicked, if any.
+    pub clicked: Option<Entity>,
+    /// The entity that was expanded or collapsed, and whether it's now
+    /// expanded.
+    pub toggled: Option<(Entity, bool)>,
+}
+
+/// A visible row of a [`TreeView`].
+struct TreeRow {
+    entity: Entity,
+    depth: usize,
+    has_children: bool,
+    open: bool,
+}
+
+/// Appends the rows of the given entity and its expanded descendants.
+fn collect_tree_rows(
+    world: &World,
+    ctx: &Context,
+    tree: Id,
+    entity: Entity,
+    depth: usize,
+    rows: &mut Vec<TreeRow>,
+) {
+    let children = world.get::<Children>(entity).filter(|c| !c.is_empty());
+    let open = children.is_some()
+        && CollapsingState::load_with_default_open(ctx, tree.with(entity), false).is_open();
+    rows.push(TreeRow {
+        entity,
+        depth,
+        has_children: children.is_some(),
+        open,
+    });
+    if let Some(children) = children.filter(|_| open) {
+        for &child in children {
+            collect_tree_rows(world, ctx, tree, child, depth + 1, rows);
+        }
+    }
+}
+
+impl<F> Widget for TreeView<'_, F>
+where
+    F: FnMut(WorldUi, Entity),
+{
+    type Out = TreeViewResponse;
+
+    fn draw(mut self, ui: WorldUi) -> Self::Out {
+        let (world, ui) = ui.into_parts();
+        let tree = ui.make_persistent_id(self.id_salt);
+        let mut rows = Vec::new();
+        for &root in &self.roots {
+            collect_tree_rows(world, ui.ctx(), tree, root, 0, &mut rows);
+        }
+
+        let row_height = ui.spacing().interact_size.y;
+        let mut out = TreeViewResponse::default();
+        ScrollArea::vertical()
+            .id_salt(tree)
+            .show_rows(ui, row_height, rows.len(), |ui, range| {
+                for row in &rows[range] {
+                    let id = tree.with(row.entity);
+                    let size = vec2(ui.available_width(), row_height);
+                    // Interact with the row before drawing its contents, so
+                    // widgets inside the row still get their own clicks.
+                    let rect = Rect::from_min_size(ui.cursor().min, size);
+                    let response = ui.interact(rect, id.with("row"), Sense::click());
+                    let fill = if self.selected == Some(row.entity) {
+                        Some(ui.visuals().selection.bg_fill)
+                    } else if response.hovered() {
+                        Some(ui.visuals().widgets.hovered.weak_bg_fill)
+                    } else {
+                        None
+                    };
+                    if let Some(fill) = fill {
+                        ui.painter().rect_filled(rect, 0.0, fill);
+                    }
+
+                    let layout = Layout::left_to_right(Align::Center);
+                    ui.allocate_ui_with_layout(size, layout, |ui| {
+                        ui.set_min_height(row_height);
+                        ui.add_space(row.depth as f32 * ui.spacing().indent);
+                        let icon_size = Vec2::splat(ui.spacing().icon_width);
+                        let sense = if row.has_children {
+                            Sense::click()
+                        } else {
+                            Sense::hover()
+                        };
+                        let (_, toggle) = ui.allocate_exact_size(icon_size, sense);
+                        if row.has_children {
+                            let openness = if row.open { 1.0 } else { 0.0 };
+                            paint_default_icon(ui, openness, &toggle);
+                            if toggle.clicked() {
+                                out.toggled = Some((row.entity, !row.open));
+                            }
+                        }
+                        (self.add_node)(WorldUi::new(world, ui), row.entity);
+                    });
+                    if response.clicked() {
+                        out.clicked = Some(row.entity);
+                    }
+                }
+            });
+
+        if let Some((entity, open)) = out.toggled {
+            let mut state =
+                CollapsingState::load_with_default_open(ui.ctx(), tree.with(entity), false);
+            state.set_open(open);
+            state.store(ui.ctx());
+            if let Some(on_expand) = &mut self.on_expand {
+                on_expand(world, entity, open);
+            }
+        }
+        if let Some(entity) = out.clicked {
+            if let Some(on_click) = &mut self.on_click {
+                on_click(world, entity);
+            }
+        }
+        out
+    }
+}
+
+/// [`Widget`] that edits a component of an entity through reflection, using
+/// the [`AppTypeRegistry`]. Numbers, strings, bools, and enums with unit
+/// variants are editable, other values are shown as read-only debug strings.
+///
+/// The component type needs to be registered with `#[reflect(Component)]`.
+/// Unregistered types are shown as read-only. The returned [`Response`] is
+/// marked as changed if any field was edited, in which case the component is
+/// marked as changed as well.
+pub struct ReflectEditor {
+    /// The entity whose component to edit.
+    pub entity: Entity,
+    /// The [`TypeId`] of the component to edit.
+    pub type_id: TypeId,
+}
+
+impl ReflectEditor {
+    /// Creates a new editor for the component with the given [`TypeId`].
+    pub fn new(entity: Entity, type_id: TypeId) -> Self {
+        ReflectEditor { entity, type_id }
+    }
+
+    /// Creates a new editor for the component `C`.
+    pub fn of<C: Component>(entity: Entity) -> Self {
+        ReflectEditor::new(entity, TypeId::of::<C>())
+    }
+}
+
+impl Widget for ReflectEditor {
+    type Out = Response;
+
+    fn draw(self, ui: WorldUi) -> Self::Out {
+        let (world, ui) = ui.into_parts();
+        let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+            warn_once!("Resource {} not found", type_name::<AppTypeRegistry>());
+            return ui.label("No type registry");
+        };
+        let registry = registry.read();
+        let reflect_component = registry
+            .get(self.type_id)
+            .and_then(|registration| registration.data::<ReflectComponent>());
+        let Some(reflect_component) = reflect_component else {
+            let name = world
+                .components()
+                .get_id(self.type_id)
+                .and_then(|id| world.components().get_info(id))
+                .map_or_else(
+                    || format!("{:?}", self.type_id),
+                    |info| info.name().to_owned(),
+                );
+            return ui.weak(format!("{name} (not reflected)"));
+        };
+        let Ok(mut entity) = world.get_entity_mut(self.entity) else {
+            warn_once!("Entity {} not found", self.entity);
+            return ui.label("No entity");
+        };
+        let Some(mut component) = reflect_component.reflect_mut(&mut entity) else {
+            return ui.weak("No component");
+        };
+
+        let value = component.bypass_change_detection().as_partial_reflect_mut();
+        let InnerResponse {
+            inner: changed,
+            mut response,
+        } = ui.push_id((self.entity, self.type_id), |ui| edit_reflect(ui, value));
+        if changed {
+            component.set_changed();
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Draws an editor for the given value, returning `true` if it was changed.
+fn edit_reflect(ui: &mut Ui, value: &mut dyn PartialReflect) -> bool {
+    let mut changed = false;
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for i in 0..value.field_len() {
+                let name = value.name_at(i).unwrap_or_default().to_owned();
+                if let Some(field) = value.field_at_mut(i) {
+                    changed |= edit_reflect_field(ui, &name, field);
+                }
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for i in 0..value.field_len() {
+                if let Some(field) = value.field_mut(i) {
+                    changed |= edit_reflect_field(ui, &i.to_string(), field);
+                }
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for i in 0..value.field_len() {
+                if let Some(field) = value.field_mut(i) {
+                    changed |= edit_reflect_field(ui, &i.to_string(), field);
+                }
+            }
+        }
+        ReflectMut::List(value) => {
+            for i in 0..value.len() {
+                if let Some(item) = value.get_mut(i) {
+                    changed |= edit_reflect_field(ui, &format!("[{i}]"), item);
+                }
+            }
+        }
+        ReflectMut::Array(value) => {
+            for i in 0..value.len() {
+                if let Some(item) = value.get_mut(i) {
+                    changed |= edit_reflect_field(ui, &format!("[{i}]"), item);
+                }
+            }
+        }
+        ReflectMut::Enum(value) => {
+            changed |= edit_reflect_variant(ui, value);
+            for i in 0..value.field_len() {
+                let name = value
+                    .name_at(i)
+                    .map_or_else(|| i.to_string(), str::to_owned);
+                if let Some(field) = value.field_at_mut(i) {
+                    changed |= edit_reflect_field(ui, &name, field);
+                }
+            }
+        }
+        ReflectMut::Opaque(value) => changed |= edit_reflect_opaque(ui, value),
+        _ => {
+            ui.weak(format!("{value:?}"));
+        }
+    }
+    changed
+}
+
+/// Draws an editor for a named field, inside a collapsing header if the
+/// field has fields of its own.
+fn edit_reflect_field(ui: &mut Ui, name: &str, field: &mut dyn PartialReflect) -> bool {
+    let is_leaf = match field.reflect_ref() {
+        ReflectRef::Opaque(_) => true,
+        ReflectRef::Enum(value) => value.field_len() == 0,
+        _ => false,
+    };
+    if is_leaf {
+        ui.horizontal(|ui| {
+            ui.label(name);
+            edit_reflect(ui, field)
+        })
+        .inner
+    } else {
+        CollapsingHeader::new(name)
+            .default_open(true)
+            .show(ui, |ui| edit_reflect(ui, field))
+            .body_returned
+            .unwrap_or(false)
+    }
+}
+
+/// Draws a combo box for switching to one of the unit variants of an enum.
+fn edit_reflect_variant(ui: &mut Ui, value: &mut dyn Enum) -> bool {
+    let current = value.variant_name().to_owned();
+    let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() else {
+        ui.label(current);
+        return false;
+    };
+    let mut selected = None;
+    ComboBox::from_id_salt(ui.id().with("variant"))
+        .selected_text(&current)
+        .show_ui(ui, |ui| {
+            for variant in info.iter() {
+                let is_current = variant.name() == current;
+                if matches!(variant, VariantInfo::Unit(_)) {
+                    if ui.selectable_label(is_current, variant.name()).clicked() {
+                        selected = Some(variant.name());
+                    }
+                } else {
+                    ui.add_enabled(false, SelectableLabel::new(is_current, variant.name()));
+                }
+            }
+        });
+    match selected {
+        Some(name) if name != current => {
+            value.apply(&DynamicEnum::new(name, DynamicVariant::Unit));
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Draws an editor for an opaque value if it's a known primitive, or its
+/// debug string otherwise.
+fn edit_reflect_opaque(ui: &mut Ui, value: &mut dyn PartialReflect) -> bool {
+    macro_rules! drag_values {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.try_downcast_mut::<$ty>() {
+                    return ui.add(DragValue::new(value)).changed();
+                }
+            )*
+        };
+    }
+    drag_values!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    if let Some(value) = value.try_downcast_mut::<bool>() {
+        return ui.checkbox(value, "").changed();
+    }
+    if let Some(value) = value.try_downcast_mut::<String>() {
+        return ui.text_edit_singleline(value).changed();
+    }
+    ui.weak(format!("{value:?}"));
+    false
+}
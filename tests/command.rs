@@ -0,0 +1,47 @@
+use bevy_ecs::{
+    schedule::{IntoSystemConfigs, Schedule},
+    system::Resource,
+    world::World,
+};
+use egui::{Context, RawInput, Window};
+use evergreen_egui::prelude::*;
+
+#[derive(Resource, Default)]
+struct Shown(Vec<&'static str>);
+
+fn first(mut ui: UiCommands) {
+    ui.show(Window::new("First"), |mut ui| {
+        ui.resource_mut::<Shown>().0.push("first");
+    });
+}
+
+fn second(mut ui: UiCommands) {
+    ui.show(Window::new("Second"), |mut ui| {
+        ui.resource_mut::<Shown>().0.push("second");
+    });
+    ui.add(|ctx| {
+        ctx.show(Window::new("Third"), |mut ui| {
+            ui.resource_mut::<Shown>().0.push("third");
+        });
+    });
+}
+
+#[test]
+fn deferred_ui_is_shown_in_schedule_order() {
+    let mut world = World::new();
+    world.init_resource::<Shown>();
+    let mut schedule = Schedule::default();
+    schedule.add_systems((second.after(first), first));
+    schedule.run(&mut world);
+
+    assert_eq!(world.resource::<DeferredUi>().len(), 3);
+    assert!(world.resource::<Shown>().0.is_empty());
+
+    let mut ctx = WorldCtx::from_context(&mut world, Context::default());
+    ctx.begin_pass(RawInput::default());
+    ctx.show_deferred();
+    ctx.end_pass();
+
+    assert_eq!(world.resource::<Shown>().0, ["first", "second", "third"]);
+    assert!(world.resource::<DeferredUi>().is_empty());
+}
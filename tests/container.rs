@@ -0,0 +1,109 @@
+use bevy_ecs::world::World;
+use egui::{Context, Event, PointerButton, Pos2, RawInput};
+use evergreen_egui::prelude::*;
+
+/// Runs a pass showing a horizontal [`Split`], returning the widths of its
+/// regions and the center of the divider.
+fn show_split(
+    world: &mut World,
+    ctx: &Context,
+    fraction: &mut f32,
+    events: Vec<Event>,
+) -> ([f32; 2], Pos2) {
+    let mut world_ctx = WorldCtx::from_context(world, ctx.clone());
+    world_ctx.simulate(events);
+    let split = Split {
+        direction: SplitDir::Horizontal,
+        fraction,
+    };
+    world_ctx
+        .run(RawInput::default(), |mut ui| {
+            ui.show(split, |mut regions| {
+                let [first, second] = [0, 1].map(|i| regions.at(i).ui().max_rect());
+                let divider = Pos2::new((first.right() + second.left()) / 2.0, first.center().y);
+                ([first.width(), second.width()], divider)
+            })
+        })
+        .1
+}
+
+#[test]
+fn split_clamps_fraction() {
+    let mut world = World::new();
+    let ctx = Context::default();
+
+    let mut fraction = 1.5;
+    let [first, second] = show_split(&mut world, &ctx, &mut fraction, Vec::new()).0;
+    assert_eq!(fraction, 1.0);
+    assert!(first > 0.0);
+    assert_eq!(second, 0.0);
+
+    let mut fraction = -0.5;
+    let [first, second] = show_split(&mut world, &ctx, &mut fraction, Vec::new()).0;
+    assert_eq!(fraction, 0.0);
+    assert_eq!(first, 0.0);
+    assert!(second > 0.0);
+}
+
+#[test]
+fn split_divider_updates_fraction() {
+    let mut world = World::new();
+    let ctx = Context::default();
+    let mut fraction = 0.5;
+
+    let ([first, second], divider) = show_split(&mut world, &ctx, &mut fraction, Vec::new());
+    let available = first + second;
+    let press = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    let moved = divider + egui::vec2(50.0, 0.0);
+    show_split(
+        &mut world,
+        &ctx,
+        &mut fraction,
+        vec![Event::PointerMoved(divider), press(divider, true)],
+    );
+    show_split(
+        &mut world,
+        &ctx,
+        &mut fraction,
+        vec![Event::PointerMoved(moved)],
+    );
+    show_split(&mut world, &ctx, &mut fraction, vec![press(moved, false)]);
+
+    assert!((fraction - (0.5 + 50.0 / available)).abs() < 1e-3);
+}
+
+/// Runs a pass showing weighted [`Columns`], returning the column widths.
+fn column_widths(weights: &[f32]) -> Vec<f32> {
+    let mut world = World::new();
+    let mut ctx = WorldCtx::from_context(&mut world, Context::default());
+    ctx.run(RawInput::default(), |mut ui| {
+        ui.show(Columns(weights), |mut columns| {
+            (0..weights.len())
+                .map(|i| columns.at(i).ui().max_rect().width())
+                .collect()
+        })
+    })
+    .1
+}
+
+#[test]
+fn weighted_columns_are_proportional() {
+    let widths = column_widths(&[1.0, 3.0]);
+    assert!((widths[1] - widths[0] * 3.0).abs() < 1e-3);
+    assert!(widths[0] > 0.0);
+}
+
+#[test]
+fn weighted_columns_handle_degenerate_weights() {
+    assert_eq!(column_widths(&[]), Vec::<f32>::new());
+    assert_eq!(column_widths(&[0.0, 0.0]), [0.0, 0.0]);
+
+    let widths = column_widths(&[-1.0, 1.0]);
+    assert_eq!(widths[0], 0.0);
+    assert!(widths[1] > 0.0);
+}
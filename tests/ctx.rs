@@ -0,0 +1,181 @@
+use bevy_ecs::{change_detection::DetectChanges, entity::Entity, system::Resource, world::World};
+use bevy_egui::{EguiContext, EguiSettings, RenderTargetSize};
+use egui::{
+    pos2, Area, Button, CentralPanel, Context, Event, FontDefinitions, FontFamily, Id,
+    PointerButton, RawInput, Rect,
+};
+use evergreen_egui::prelude::*;
+
+#[derive(Resource, Default)]
+struct Clicks(u32);
+
+/// Runs a pass with a button in a central panel, counting its clicks in the
+/// [`Clicks`] resource, and returns the button's rect.
+fn show_button(world: &mut World, ctx: &Context, events: Vec<Event>) -> Rect {
+    let mut world_ctx = WorldCtx::from_context(world, ctx.clone());
+    world_ctx.simulate(events);
+    world_ctx.begin_pass(RawInput::default());
+    let rect = world_ctx.show(CentralPanel::default(), |mut ui| {
+        let response = ui.ui_mut().add(Button::new("Click me"));
+        if response.clicked() {
+            ui.resource_mut::<Clicks>().0 += 1;
+        }
+        response.rect
+    });
+    world_ctx.end_pass();
+    rect.inner
+}
+
+#[test]
+fn from_context_runs_without_egui_plugin() {
+    let mut world = World::new();
+    world.init_resource::<Clicks>();
+    assert_eq!(
+        WorldCtx::try_new(&mut world).err(),
+        Some(CtxError::PluginNotAdded)
+    );
+
+    let mut ctx = WorldCtx::from_context(&mut world, Context::default());
+    let (_, shown) = ctx.run(RawInput::default(), |mut ui| {
+        ui.resource_mut::<Clicks>().0 += 1;
+        ui.ui_mut().label("Headless").rect.is_positive()
+    });
+    assert!(shown);
+    assert_eq!(world.resource::<Clicks>().0, 1);
+}
+
+#[test]
+fn simulated_click_changes_resource() {
+    let mut world = World::new();
+    world.init_resource::<Clicks>();
+    let ctx = Context::default();
+
+    let center = show_button(&mut world, &ctx, Vec::new()).center();
+    let button = |pressed| Event::PointerButton {
+        pos: center,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    show_button(
+        &mut world,
+        &ctx,
+        vec![Event::PointerMoved(center), button(true)],
+    );
+    assert_eq!(world.resource::<Clicks>().0, 0);
+    show_button(&mut world, &ctx, vec![button(false)]);
+    assert_eq!(world.resource::<Clicks>().0, 1);
+
+    // Released away from the button, so this isn't a click.
+    show_button(&mut world, &ctx, vec![button(true)]);
+    show_button(
+        &mut world,
+        &ctx,
+        vec![Event::PointerMoved(pos2(-10.0, -10.0))],
+    );
+    let away = Event::PointerButton {
+        pos: pos2(-10.0, -10.0),
+        button: PointerButton::Primary,
+        pressed: false,
+        modifiers: Default::default(),
+    };
+    show_button(&mut world, &ctx, vec![away]);
+    assert_eq!(world.resource::<Clicks>().0, 1);
+}
+
+/// Shows a disabled or enabled area in the top left corner with the pointer
+/// over it, and returns whether egui wants the pointer afterwards.
+fn wants_pointer_over_area(disabled: bool) -> bool {
+    let mut world = World::new();
+    let ctx = Context::default();
+    for events in [Vec::new(), vec![Event::PointerMoved(pos2(5.0, 5.0))]] {
+        let mut world_ctx = WorldCtx::from_context(&mut world, ctx.clone());
+        world_ctx.simulate(events);
+        world_ctx.run_passes(RawInput::default(), |world_ctx| {
+            let area = Area::new(Id::new("area")).fixed_pos(pos2(0.0, 0.0));
+            world_ctx.show_disabled_if(disabled, area, |mut ui| {
+                ui.ui_mut().label("Some long enough text");
+            });
+        });
+    }
+    ctx.is_pointer_over_area() || ctx.wants_pointer_input()
+}
+
+#[test]
+fn disabled_area_passes_pointer_through() {
+    assert!(wants_pointer_over_area(false));
+    assert!(!wants_pointer_over_area(true));
+}
+
+#[test]
+fn fonts_are_only_rebuilt_when_changed() {
+    let mut world = World::new();
+    let (a, b) = (Context::default(), Context::default());
+    let data = || FontDefinitions::default().font_data["Hack"].font.to_vec();
+    let mut install = |ctx: &Context, family: FontFamily| {
+        WorldCtx::from_context(&mut world, ctx.clone()).install_font("custom", data(), family)
+    };
+
+    assert!(install(&a, FontFamily::Proportional));
+    assert!(!install(&a, FontFamily::Proportional));
+    // Each context tracks what was installed on it.
+    assert!(install(&b, FontFamily::Proportional));
+    assert!(!install(&b, FontFamily::Proportional));
+    // Changing the family reinstalls the font everywhere.
+    assert!(install(&a, FontFamily::Monospace));
+    assert!(install(&b, FontFamily::Monospace));
+    assert!(!install(&a, FontFamily::Monospace));
+
+    let names: Vec<_> = world.resource::<InstalledFonts>().names().collect();
+    assert_eq!(names, ["custom"]);
+
+    let mut ctx = WorldCtx::from_context(&mut world, a.clone());
+    assert!(ctx.uninstall_font("custom"));
+    assert!(!ctx.uninstall_font("custom"));
+    assert_eq!(world.resource::<InstalledFonts>().names().count(), 0);
+
+    // The rebuilt fonts are usable.
+    let _ = a.run(RawInput::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| ui.label("Text"));
+    });
+}
+
+/// Spawns a `bevy_egui` context with a render target scale factor of 2 and
+/// returns its entity and egui context.
+fn spawn_egui_context(world: &mut World) -> (Entity, Context) {
+    let mut ctx = EguiContext::default();
+    let egui_ctx = ctx.get_mut().clone();
+    let size = RenderTargetSize {
+        physical_width: 800.0,
+        physical_height: 600.0,
+        scale_factor: 2.0,
+    };
+    let entity = world.spawn((ctx, EguiSettings::default(), size)).id();
+    (entity, egui_ctx)
+}
+
+#[test]
+fn set_pixels_per_point_only_changes_own_settings() {
+    let mut world = World::new();
+    let (a, ctx) = spawn_egui_context(&mut world);
+    let (b, _) = spawn_egui_context(&mut world);
+    let changed = |world: &World, entity: Entity| {
+        let entity = world.entity(entity);
+        (
+            entity.get_ref::<EguiContext>().unwrap().is_changed(),
+            entity.get_ref::<EguiSettings>().unwrap().is_changed(),
+        )
+    };
+
+    world.clear_trackers();
+    WorldCtx::from_context(&mut world, ctx.clone()).set_pixels_per_point(3.0);
+    assert_eq!(world.get::<EguiSettings>(a).unwrap().scale_factor, 1.5);
+    assert_eq!(world.get::<EguiSettings>(b).unwrap().scale_factor, 1.0);
+    assert_eq!(changed(&world, a), (false, true));
+    assert_eq!(changed(&world, b), (false, false));
+
+    // Setting the same value again doesn't change anything.
+    world.clear_trackers();
+    WorldCtx::from_context(&mut world, ctx).set_pixels_per_point(3.0);
+    assert_eq!(changed(&world, a), (false, false));
+}
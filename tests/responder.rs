@@ -0,0 +1,67 @@
+use bevy_ecs::{system::Resource, world::World};
+use egui::{Button, Context, Event, PointerButton, RawInput, Rect};
+use evergreen_egui::prelude::*;
+
+#[derive(Resource, Default)]
+struct Clicks(u32);
+
+/// Runs a pass with a button that counts its clicks with [`SetResource`], and
+/// returns the button's rect.
+fn show_button(world: &mut World, ctx: &Context, events: Vec<Event>) -> Rect {
+    let mut world_ctx = WorldCtx::from_context(world, ctx.clone());
+    world_ctx.simulate(events);
+    world_ctx
+        .run(RawInput::default(), |mut ui| {
+            let responder = SetResource::new(|clicks: &mut Clicks, response| {
+                if response.clicked() {
+                    clicks.0 += 1;
+                }
+            });
+            ui.add_with(Button::new("Click me"), responder).rect
+        })
+        .1
+}
+
+#[test]
+fn set_resource_responds_to_clicks() {
+    let mut world = World::new();
+    world.init_resource::<Clicks>();
+    let ctx = Context::default();
+
+    let center = show_button(&mut world, &ctx, Vec::new()).center();
+    let button = |pressed| Event::PointerButton {
+        pos: center,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    show_button(
+        &mut world,
+        &ctx,
+        vec![Event::PointerMoved(center), button(true)],
+    );
+    assert_eq!(world.resource::<Clicks>().0, 0);
+    show_button(&mut world, &ctx, vec![button(false)]);
+    assert_eq!(world.resource::<Clicks>().0, 1);
+}
+
+#[test]
+fn set_resource_ignores_missing_resource() {
+    let mut world = World::new();
+    let ctx = Context::default();
+
+    let center = show_button(&mut world, &ctx, Vec::new()).center();
+    let button = |pressed| Event::PointerButton {
+        pos: center,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    show_button(
+        &mut world,
+        &ctx,
+        vec![Event::PointerMoved(center), button(true)],
+    );
+    show_button(&mut world, &ctx, vec![button(false)]);
+    assert!(!world.contains_resource::<Clicks>());
+}
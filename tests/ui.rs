@@ -0,0 +1,273 @@
+use bevy_ecs::{
+    component::Component,
+    query::With,
+    system::{InMut, RegisteredSystemError},
+    world::World,
+};
+use egui::{Context, Event, Key, Modifiers, PointerButton, Pos2, RawInput, Rect, Response, Ui};
+use evergreen_egui::prelude::*;
+
+fn key_events(key: Key) -> Vec<Event> {
+    let event = |pressed| Event::Key {
+        key,
+        physical_key: None,
+        pressed,
+        repeat: false,
+        modifiers: Modifiers::NONE,
+    };
+    vec![event(true), event(false)]
+}
+
+/// Drives a [`WorldUi::select_list`] over three items.
+struct SelectList {
+    world: World,
+    ctx: Context,
+    current: u8,
+}
+
+impl SelectList {
+    fn new() -> Self {
+        SelectList {
+            world: World::new(),
+            ctx: Context::default(),
+            current: 0,
+        }
+    }
+
+    /// Runs a pass with the given events, returning whether the selection
+    /// changed and whether an arrow key was left in the input afterwards.
+    fn pass(&mut self, events: Vec<Event>) -> (bool, bool) {
+        let items = [(0, "Zero".into()), (1, "One".into()), (2, "Two".into())];
+        let mut ctx = WorldCtx::from_context(&mut self.world, self.ctx.clone());
+        ctx.simulate(events);
+        ctx.run(RawInput::default(), |mut ui| {
+            let changed = ui.select_list(&items, &mut self.current).changed();
+            let leaked = ui.ui().input(|input| {
+                input.key_pressed(Key::ArrowDown) || input.key_pressed(Key::ArrowUp)
+            });
+            (changed, leaked)
+        })
+        .1
+    }
+
+    /// Presses the given key, then lets the focus settle.
+    fn press(&mut self, key: Key) -> (bool, bool) {
+        let out = self.pass(key_events(key));
+        for _ in 0..2 {
+            self.pass(Vec::new());
+        }
+        out
+    }
+
+    /// Focuses the first item.
+    fn focus(&mut self) {
+        self.pass(Vec::new());
+        self.press(Key::Tab);
+    }
+}
+
+#[test]
+fn select_list_moves_with_arrow_keys() {
+    let mut list = SelectList::new();
+    list.focus();
+
+    assert_eq!(list.press(Key::ArrowDown), (true, false));
+    assert_eq!(list.current, 1);
+    assert_eq!(list.press(Key::ArrowDown), (true, false));
+    assert_eq!(list.current, 2);
+    assert_eq!(list.press(Key::ArrowUp), (true, false));
+    assert_eq!(list.current, 1);
+}
+
+#[test]
+fn select_list_stays_within_bounds() {
+    let mut list = SelectList::new();
+    list.focus();
+
+    // Up on the first item keeps it selected, and still consumes the key.
+    assert_eq!(list.press(Key::ArrowUp), (false, false));
+    assert_eq!(list.current, 0);
+
+    list.press(Key::ArrowDown);
+    list.press(Key::ArrowDown);
+    assert_eq!(list.current, 2);
+    assert_eq!(list.press(Key::ArrowDown), (false, false));
+    assert_eq!(list.current, 2);
+}
+
+#[test]
+fn select_list_ignores_arrows_without_focus() {
+    let mut list = SelectList::new();
+    list.pass(Vec::new());
+
+    assert_eq!(list.press(Key::ArrowDown), (false, true));
+    assert_eq!(list.current, 0);
+}
+
+#[test]
+fn select_list_selects_focused_item_with_enter() {
+    let mut list = SelectList::new();
+    list.focus();
+    // Tab moves the focus without changing the selection.
+    assert_eq!(list.press(Key::Tab), (false, false));
+    assert_eq!(list.current, 0);
+
+    assert_eq!(list.press(Key::Enter), (true, false));
+    assert_eq!(list.current, 1);
+}
+
+#[derive(Component)]
+struct Enemy;
+
+#[test]
+fn for_query_allows_mutating_the_world() {
+    let mut world = World::new();
+    let enemies: Vec<_> = (0..3).map(|_| world.spawn(Enemy).id()).collect();
+    world.spawn_empty();
+
+    let mut ctx = WorldCtx::from_context(&mut world, Context::default());
+    let (_, visited) = ctx.run(RawInput::default(), |mut ui| {
+        let mut visited = Vec::new();
+        ui.for_query::<(), With<Enemy>>(|mut ui, entity| {
+            visited.push(entity);
+            ui.despawn(entity);
+        });
+        visited
+    });
+
+    assert_eq!(visited, enemies);
+    assert_eq!(world.query::<&Enemy>().iter(&world).count(), 0);
+}
+
+fn score_label(InMut(ui): InMut<Ui>) -> Response {
+    ui.label("Score")
+}
+
+#[test]
+fn ui_system_error_keeps_the_system_entity() {
+    let mut world = World::new();
+    let id = world.register_system(score_label);
+    let entity = id.entity();
+
+    let errors = [
+        (
+            RegisteredSystemError::SystemIdNotRegistered(id),
+            UiSystemError::NotRegistered(entity),
+        ),
+        (
+            RegisteredSystemError::SystemNotCached,
+            UiSystemError::NotCached,
+        ),
+        (
+            RegisteredSystemError::Recursive(id),
+            UiSystemError::Recursive(entity),
+        ),
+        (
+            RegisteredSystemError::SelfRemove(id),
+            UiSystemError::SelfRemove(entity),
+        ),
+        (
+            RegisteredSystemError::InvalidParams(id),
+            UiSystemError::InvalidParams(entity),
+        ),
+    ];
+    for (err, expected) in errors {
+        assert_eq!(UiSystemError::from(err), expected);
+    }
+
+    let mut ctx = WorldCtx::from_context(&mut world, Context::default());
+    let (_, result) = ctx.run(RawInput::default(), |mut ui| {
+        ui.run_cached(score_label).map(|response| response.rect)
+    });
+    assert!(result.is_ok());
+}
+
+/// Rects of the buttons in the nested menus that were shown in a pass.
+struct Menus {
+    settings: Rect,
+    graphics: Option<Rect>,
+    resolution: Option<Rect>,
+    close: Option<Rect>,
+}
+
+/// Runs a pass showing Settings → Graphics → Resolution menus, where the
+/// innermost menu has a button that closes all menus.
+fn show_menus(world: &mut World, ctx: &Context, events: Vec<Event>) -> Menus {
+    let mut world_ctx = WorldCtx::from_context(world, ctx.clone());
+    world_ctx.simulate(events);
+    let mut menus = Menus {
+        settings: Rect::NOTHING,
+        graphics: None,
+        resolution: None,
+        close: None,
+    };
+    world_ctx.run(RawInput::default(), |mut ui| {
+        let settings = ui.menu("Settings", |mut ui| {
+            let graphics = ui.menu("Graphics", |mut ui| {
+                let resolution = ui.menu("Resolution", |mut ui| {
+                    let close = ui.ui_mut().button("Close");
+                    if close.clicked() {
+                        ui.close_menu();
+                    }
+                    menus.close = Some(close.rect);
+                });
+                menus.resolution = Some(resolution.response.rect);
+            });
+            menus.graphics = Some(graphics.response.rect);
+        });
+        menus.settings = settings.response.rect;
+    });
+    menus
+}
+
+/// Moves the pointer to the given position, then lets the menus settle.
+fn hover(world: &mut World, ctx: &Context, pos: Pos2) -> Menus {
+    show_menus(world, ctx, vec![Event::PointerMoved(pos)]);
+    show_menus(world, ctx, Vec::new());
+    show_menus(world, ctx, Vec::new())
+}
+
+/// Clicks at the given position, then lets the menus settle.
+fn click_menu(world: &mut World, ctx: &Context, pos: Pos2) -> Menus {
+    let button = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    };
+    show_menus(world, ctx, vec![Event::PointerMoved(pos), button(true)]);
+    show_menus(world, ctx, vec![button(false)]);
+    show_menus(world, ctx, Vec::new())
+}
+
+/// Opens all menus down to the innermost one.
+fn open_menus(world: &mut World, ctx: &Context) -> Menus {
+    let menus = show_menus(world, ctx, Vec::new());
+    let menus = click_menu(world, ctx, menus.settings.center());
+    let menus = hover(world, ctx, menus.graphics.unwrap().center());
+    let menus = hover(world, ctx, menus.resolution.unwrap().center());
+    assert!(menus.close.is_some());
+    menus
+}
+
+#[test]
+fn menus_nest_and_close_together() {
+    let mut world = World::new();
+    let ctx = Context::default();
+
+    let menus = show_menus(&mut world, &ctx, Vec::new());
+    assert!(menus.graphics.is_none());
+
+    // Closing the innermost menu closes all of them.
+    let menus = open_menus(&mut world, &ctx);
+    let menus = click_menu(&mut world, &ctx, menus.close.unwrap().center());
+    assert!(menus.graphics.is_none());
+    assert!(menus.resolution.is_none());
+    assert!(menus.close.is_none());
+
+    // So does closing the top level menu.
+    let menus = open_menus(&mut world, &ctx);
+    let menus = click_menu(&mut world, &ctx, menus.settings.center());
+    assert!(menus.graphics.is_none());
+    assert!(menus.close.is_none());
+}
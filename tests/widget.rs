@@ -0,0 +1,299 @@
+use std::any::TypeId;
+
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    system::Resource,
+    world::World,
+};
+use bevy_hierarchy::BuildChildren;
+use bevy_reflect::Reflect;
+use egui::{vec2, Button, Context, Event, PointerButton, Pos2, RawInput, Rect, Response};
+use evergreen_egui::prelude::*;
+
+fn click(pos: Pos2) -> [Vec<Event>; 2] {
+    let button = |pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Default::default(),
+    };
+    [
+        vec![Event::PointerMoved(pos), button(true)],
+        vec![button(false)],
+    ]
+}
+
+#[derive(Resource, Default)]
+struct TreeLog {
+    clicked: Vec<Entity>,
+    expanded: Vec<(Entity, bool)>,
+}
+
+/// Runs a pass showing a [`TreeView`] of the given roots, returning the rows
+/// that were drawn along with their rects.
+fn show_tree(
+    world: &mut World,
+    ctx: &Context,
+    roots: &[Entity],
+    events: Vec<Event>,
+) -> Vec<(Entity, Rect)> {
+    let mut world_ctx = WorldCtx::from_context(world, ctx.clone());
+    world_ctx.simulate(events);
+    let raw_input = RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 300.0))),
+        ..Default::default()
+    };
+    let mut drawn = Vec::new();
+    world_ctx.run(raw_input, |mut ui| {
+        let tree = TreeView::new("tree", roots.iter().copied(), |mut ui, entity| {
+            drawn.push((entity, ui.ui().max_rect()));
+            ui.ui_mut().label(format!("{entity}"));
+        })
+        .on_click(|world, entity| world.resource_mut::<TreeLog>().clicked.push(entity))
+        .on_expand(|world, entity, open| {
+            world
+                .resource_mut::<TreeLog>()
+                .expanded
+                .push((entity, open))
+        });
+        ui.add(tree);
+    });
+    drawn
+}
+
+#[test]
+fn tree_view_expands_and_clicks() {
+    let mut world = World::new();
+    world.init_resource::<TreeLog>();
+    let child = world.spawn_empty().id();
+    let root = world.spawn_empty().add_child(child).id();
+    let ctx = Context::default();
+
+    let rows = show_tree(&mut world, &ctx, &[root], Vec::new());
+    assert_eq!(rows.iter().map(|(e, _)| *e).collect::<Vec<_>>(), [root]);
+
+    // Expand the root with its toggle at the start of the row.
+    let row = rows[0].1;
+    let toggle = row.left_center() + vec2(ctx.style().spacing.icon_width / 2.0, 0.0);
+    for events in click(toggle) {
+        show_tree(&mut world, &ctx, &[root], events);
+    }
+    assert_eq!(world.resource::<TreeLog>().expanded, [(root, true)]);
+    assert!(world.resource::<TreeLog>().clicked.is_empty());
+    let rows = show_tree(&mut world, &ctx, &[root], Vec::new());
+    assert_eq!(
+        rows.iter().map(|(e, _)| *e).collect::<Vec<_>>(),
+        [root, child]
+    );
+
+    // Click the child's row outside of its contents.
+    let row = rows[1].1;
+    for events in click(row.right_center() - vec2(10.0, 0.0)) {
+        show_tree(&mut world, &ctx, &[root], events);
+    }
+    assert_eq!(world.resource::<TreeLog>().clicked, [child]);
+
+    // Collapse the root again.
+    for events in click(toggle) {
+        show_tree(&mut world, &ctx, &[root], events);
+    }
+    assert_eq!(
+        world.resource::<TreeLog>().expanded,
+        [(root, true), (root, false)]
+    );
+    let rows = show_tree(&mut world, &ctx, &[root], Vec::new());
+    assert_eq!(rows.len(), 1);
+}
+
+#[test]
+fn tree_view_only_draws_visible_rows() {
+    let mut world = World::new();
+    world.init_resource::<TreeLog>();
+    let roots: Vec<_> = (0..1000).map(|_| world.spawn_empty().id()).collect();
+    let ctx = Context::default();
+
+    let rows = show_tree(&mut world, &ctx, &roots, Vec::new());
+    assert!(!rows.is_empty());
+    assert!(rows.len() < 100);
+    assert_eq!(rows[0].0, roots[0]);
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Visible {
+    on: bool,
+}
+
+#[derive(Component, Default)]
+struct Unreflected(u32);
+
+#[derive(Reflect, Default)]
+enum Mode {
+    #[default]
+    Idle,
+    Moving {
+        speed: f32,
+    },
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Everything {
+    name: String,
+    count: u8,
+    scale: (f32, f64),
+    tags: Vec<String>,
+    corners: [i32; 2],
+    mode: Mode,
+    moving: Mode,
+    entity: Option<Entity>,
+}
+
+/// Runs a pass showing a [`ReflectEditor`] for the given component.
+fn show_editor(
+    world: &mut World,
+    ctx: &Context,
+    entity: Entity,
+    type_id: TypeId,
+    events: Vec<Event>,
+) -> Response {
+    let mut world_ctx = WorldCtx::from_context(world, ctx.clone());
+    world_ctx.simulate(events);
+    world_ctx
+        .run(RawInput::default(), |mut ui| {
+            ui.add(ReflectEditor::new(entity, type_id))
+        })
+        .1
+}
+
+#[test]
+fn reflect_editor_edits_registered_component() {
+    let mut world = World::new();
+    world.init_resource::<AppTypeRegistry>();
+    world
+        .resource::<AppTypeRegistry>()
+        .write()
+        .register::<Visible>();
+    let entity = world.spawn(Visible::default()).id();
+    let type_id = TypeId::of::<Visible>();
+    let ctx = Context::default();
+
+    world.clear_trackers();
+    let response = show_editor(&mut world, &ctx, entity, type_id, Vec::new());
+    assert!(!response.changed());
+    assert!(!world
+        .entity(entity)
+        .get_ref::<Visible>()
+        .unwrap()
+        .is_changed());
+
+    // The checkbox of the only field is at the end of its row.
+    let checkbox = response.rect.right_center() - vec2(ctx.style().spacing.icon_width / 2.0, 0.0);
+    let [press, release] = click(checkbox);
+    show_editor(&mut world, &ctx, entity, type_id, press);
+    let response = show_editor(&mut world, &ctx, entity, type_id, release);
+    assert!(response.changed());
+    let visible = world.entity(entity).get_ref::<Visible>().unwrap();
+    assert!(visible.on);
+    assert!(visible.is_changed());
+}
+
+#[test]
+fn reflect_editor_shows_unregistered_component_read_only() {
+    let mut world = World::new();
+    world.init_resource::<AppTypeRegistry>();
+    let entity = world.spawn((Visible::default(), Unreflected(7))).id();
+    let ctx = Context::default();
+
+    // Neither component is registered, so both are read-only.
+    for type_id in [TypeId::of::<Visible>(), TypeId::of::<Unreflected>()] {
+        let response = show_editor(&mut world, &ctx, entity, type_id, Vec::new());
+        let [press, release] = click(response.rect.center());
+        show_editor(&mut world, &ctx, entity, type_id, press);
+        let response = show_editor(&mut world, &ctx, entity, type_id, release);
+        assert!(!response.changed());
+    }
+    assert!(!world.get::<Visible>(entity).unwrap().on);
+    assert_eq!(world.get::<Unreflected>(entity).unwrap().0, 7);
+}
+
+#[test]
+fn reflect_editor_handles_missing_registry_and_entity() {
+    let mut world = World::new();
+    let entity = world.spawn(Visible::default()).id();
+    let type_id = TypeId::of::<Visible>();
+    let ctx = Context::default();
+
+    let response = show_editor(&mut world, &ctx, entity, type_id, Vec::new());
+    assert!(!response.changed());
+
+    world.init_resource::<AppTypeRegistry>();
+    world
+        .resource::<AppTypeRegistry>()
+        .write()
+        .register::<Visible>();
+    world.despawn(entity);
+    let response = show_editor(&mut world, &ctx, entity, type_id, Vec::new());
+    assert!(!response.changed());
+}
+
+#[test]
+fn reflect_editor_shows_all_kinds_of_values() {
+    let mut world = World::new();
+    world.init_resource::<AppTypeRegistry>();
+    world
+        .resource::<AppTypeRegistry>()
+        .write()
+        .register::<Everything>();
+    let everything = Everything {
+        tags: vec!["a".into(), "b".into()],
+        moving: Mode::Moving { speed: 1.0 },
+        ..Default::default()
+    };
+    let entity = world.spawn(everything).id();
+    let ctx = Context::default();
+
+    world.clear_trackers();
+    let type_id = TypeId::of::<Everything>();
+    let response = show_editor(&mut world, &ctx, entity, type_id, Vec::new());
+    assert!(!response.changed());
+    assert!(response.rect.height() > 0.0);
+    let everything = world.entity(entity).get_ref::<Everything>().unwrap();
+    assert!(!everything.is_changed());
+}
+
+#[test]
+fn measured_sizes_match_drawn_sizes() {
+    let mut world = World::new();
+    let mut ctx = WorldCtx::from_context(&mut world, Context::default());
+    ctx.run(RawInput::default(), |mut ui| {
+        let label = TextLabel("Some label".into());
+        let measured = label.measure(ui.ui());
+        assert_eq!(measured, Some(ui.add(label).rect.size()));
+
+        let button = TextButton("Some button".into());
+        let measured = button.measure(ui.ui());
+        assert_eq!(measured, Some(ui.add(button).rect.size()));
+
+        // Widgets from egui can't be measured without drawing them.
+        let button = Button::new("Some button").into_widget();
+        assert_eq!(button.measure(ui.ui()), None);
+    });
+}
+
+#[test]
+fn add_centered_centers_measurable_widgets() {
+    let mut world = World::new();
+    let mut ctx = WorldCtx::from_context(&mut world, Context::default());
+    ctx.run(RawInput::default(), |mut ui| {
+        let available = ui.ui().available_rect_before_wrap();
+        let rect = ui.add_centered(TextButton("Centered".into())).rect;
+        assert!((rect.center().x - available.center().x).abs() < 1.0);
+
+        let rect = ui.add_centered(Button::new("Left-aligned")).rect;
+        assert_eq!(rect.left(), available.left());
+    });
+}